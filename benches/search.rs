@@ -0,0 +1,164 @@
+#[macro_use]
+extern crate criterion;
+extern crate net_pathfinder;
+
+use criterion::Criterion;
+use net_pathfinder::net::Net;
+use net_pathfinder::node::Node;
+use net_pathfinder::node::NodeBuilder;
+use net_pathfinder::node::Point;
+use std::hint::black_box;
+
+const GRID_WIDTH: u32 = 5;
+const GRID_HEIGHT: u32 = 4;
+const LARGE_GRID_SIZE: u32 = 41;
+const LARGE_CHAIN_LENGTH: u32 = 3000;
+#[cfg(feature = "rayon")]
+const COMPLETE_NET_SIZE: u32 = 8;
+
+#[derive(Clone, Debug)]
+struct GridPoint {
+    name: String
+}
+
+impl Point for GridPoint {
+    type Identifier = String;
+
+    fn id(&self) -> &String {
+        &self.name
+    }
+}
+
+fn grid_point(x: u32, y: u32) -> GridPoint {
+    GridPoint { name: format!("point at column {} and row {}", x, y) }
+}
+
+fn grid_node(x: u32, y: u32, width: u32, height: u32) -> Node<GridPoint> {
+    let mut neighbours = Vec::new();
+    if x > 0 { neighbours.push(grid_point(x - 1, y)); }
+    if x + 1 < width { neighbours.push(grid_point(x + 1, y)); }
+    if y > 0 { neighbours.push(grid_point(x, y - 1)); }
+    if y + 1 < height { neighbours.push(grid_point(x, y + 1)); }
+
+    NodeBuilder::new()
+        .point(&grid_point(x, y))
+        .connected_points(&neighbours)
+        .build()
+        .unwrap()
+}
+
+fn grid_net(width: u32, height: u32) -> Net<GridPoint> {
+    let mut nodes = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            nodes.push(grid_node(x, y, width, height));
+        }
+    }
+
+    Net::new(nodes)
+}
+
+#[cfg(feature = "rayon")]
+fn complete_point(number: u32) -> GridPoint {
+    GridPoint { name: format!("point {}", number) }
+}
+
+#[cfg(feature = "rayon")]
+fn complete_net() -> Net<GridPoint> {
+    let nodes = (0..COMPLETE_NET_SIZE)
+        .map(|number| {
+            let neighbours: Vec<GridPoint> = (0..COMPLETE_NET_SIZE)
+                .filter(|&other_number| other_number != number)
+                .map(complete_point)
+                .collect();
+
+            NodeBuilder::new()
+                .point(&complete_point(number))
+                .connected_points(&neighbours)
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    Net::new(nodes)
+}
+
+fn chain_point(number: u32) -> GridPoint {
+    GridPoint { name: format!("point {} of the chain", number) }
+}
+
+fn chain_net(length: u32) -> Net<GridPoint> {
+    Net::from_edges((1..length).map(|number| (chain_point(number - 1), chain_point(number))))
+}
+
+fn net_vs_frozen_net(c: &mut Criterion) {
+    let origin = grid_point(0, 0);
+    let destination = grid_point(GRID_WIDTH - 1, GRID_HEIGHT - 1);
+    let net = grid_net(GRID_WIDTH, GRID_HEIGHT);
+    let frozen_net = grid_net(GRID_WIDTH, GRID_HEIGHT).freeze().unwrap();
+
+    let mut group = c.benchmark_group("find_paths in a grid");
+    group.bench_function("Net", |b| b.iter(|| net.find_paths(black_box(&origin), black_box(&destination))));
+    group.bench_function("FrozenNet", |b| b.iter(|| frozen_net.find_paths(black_box(&origin), black_box(&destination))));
+    group.finish();
+}
+
+fn net_vs_indexed_net(c: &mut Criterion) {
+    let origin = grid_point(0, 0);
+    let destination = grid_point(GRID_WIDTH - 1, GRID_HEIGHT - 1);
+    let net = grid_net(GRID_WIDTH, GRID_HEIGHT);
+    let (indexed_net, _) = net.to_indexed().unwrap();
+    let indexed_destination = GRID_WIDTH * GRID_HEIGHT - 1;
+
+    let mut group = c.benchmark_group("find_paths in a grid with string ids");
+    group.bench_function("Net", |b| b.iter(|| net.find_paths(black_box(&origin), black_box(&destination))));
+    group.bench_function("IndexedNet", |b| b.iter(|| indexed_net.find_paths(black_box(0), black_box(indexed_destination))));
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn serial_vs_parallel(c: &mut Criterion) {
+    let origin = complete_point(0);
+    let destination = complete_point(COMPLETE_NET_SIZE - 1);
+    let net = complete_net();
+
+    let mut group = c.benchmark_group("find_paths in a fully connected net");
+    group.bench_function("serial", |b| b.iter(|| net.find_paths(black_box(&origin), black_box(&destination))));
+    group.bench_function("parallel", |b| b.iter(|| net.par_find_paths(black_box(&origin), black_box(&destination))));
+    group.finish();
+}
+
+fn dijkstra_vs_bidirectional(c: &mut Criterion) {
+    // Both points lie well inside the grid, so Dijkstra expands a diamond twice as wide as each
+    // of the two diamonds the bidirectional search expands before they meet
+    let middle = LARGE_GRID_SIZE / 2;
+    let origin = grid_point(middle / 2, middle);
+    let destination = grid_point(middle + middle / 2, middle);
+    let net = grid_net(LARGE_GRID_SIZE, LARGE_GRID_SIZE);
+
+    let dijkstra_expansions = net.shortest_path_expansions(&origin, &destination).unwrap();
+    let bidirectional_expansions = net.shortest_path_bidirectional_expansions(&origin, &destination).unwrap();
+    println!("shortest path in a large grid: Dijkstra expands {} points, bidirectional search expands {}", dijkstra_expansions, bidirectional_expansions);
+
+    let mut group = c.benchmark_group("shortest path in a large grid");
+    group.bench_function("Dijkstra", |b| b.iter(|| net.shortest_path(black_box(&origin), black_box(&destination))));
+    group.bench_function("bidirectional", |b| b.iter(|| net.shortest_path_bidirectional(black_box(&origin), black_box(&destination))));
+    group.finish();
+}
+
+fn indexed_vs_scanned_lookups(c: &mut Criterion) {
+    let last_point = chain_point(LARGE_CHAIN_LENGTH - 1);
+    let net = chain_net(LARGE_CHAIN_LENGTH);
+
+    // Scanning the nodes is how the net looked points up before it indexed them
+    let mut group = c.benchmark_group("point lookups in a large net");
+    group.bench_function("indexed", |b| b.iter(|| net.degree_of(black_box(&last_point))));
+    group.bench_function("scanned", |b| b.iter(|| net.nodes().iter().find(|node| node.point_is(black_box(&last_point))).map(Node::degree)));
+    group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+criterion_group!(benches, net_vs_frozen_net, net_vs_indexed_net, dijkstra_vs_bidirectional, indexed_vs_scanned_lookups);
+#[cfg(feature = "rayon")]
+criterion_group!(benches, net_vs_frozen_net, net_vs_indexed_net, dijkstra_vs_bidirectional, indexed_vs_scanned_lookups, serial_vs_parallel);
+criterion_main!(benches);
@@ -0,0 +1,158 @@
+use net::Net;
+use net::NetErrors;
+use node::NodeBuilder;
+use node::Point;
+
+impl<T: Point> Net<T> {
+    /// GraphViz DOT description of the net, labelling every point with its id. Nets with
+    /// one-way connections are written as a `digraph`, listing every connection on its own.
+    pub fn to_dot(&self) -> String {
+        let directed = self.is_directed();
+        let (kind, edge_operator) = if directed { ("digraph", "->") } else { ("graph", "--") };
+
+        let edges: Vec<(String, String)> = if directed {
+            self.nodes().iter()
+                .flat_map(|node| node.connected_points().into_iter()
+                    .map(move |connected_point| (node.point().id().to_string(), connected_point.id().to_string())))
+                .collect()
+        } else {
+            self.to_edge_list().into_iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect()
+        };
+
+        let mut dot = format!("{} {{\n", kind);
+        for node in self.nodes() {
+            let id = node.point().id().to_string();
+            if !edges.iter().any(|(from, to)| *from == id || *to == id) {
+                dot.push_str(&format!("    {};\n", quoted_id(&id)));
+            }
+        }
+        for (from, to) in &edges {
+            dot.push_str(&format!("    {} {} {};\n", quoted_id(from), edge_operator, quoted_id(to)));
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+impl Net<char> {
+    /// Reads an undirected DOT graph whose point ids are single characters, such as
+    /// `graph { A -- B -- C; D }`. Attribute lists are ignored.
+    pub fn from_dot(dot: &str) -> Result<Net<char>, NetErrors> {
+        let dot = dot.trim();
+        if dot.starts_with("digraph") {
+            return Err(NetErrors::InvalidFormat(String::from("only undirected graphs can be read")));
+        }
+
+        let body = match (dot.find('{'), dot.rfind('}')) {
+            (Some(start), Some(end)) if dot[..start].trim_start_matches("strict").trim().starts_with("graph") && start < end => &dot[start + 1..end],
+            _ => return Err(NetErrors::InvalidFormat(String::from("expected graph { ... }")))
+        };
+
+        let mut points = Vec::new();
+        let mut edges = Vec::new();
+        for statement in without_attributes(body).split([';', '\n']) {
+            let statement = statement.trim();
+            if statement.is_empty() {
+                continue;
+            }
+
+            let chained_points = statement.split("--")
+                .map(point_from_id)
+                .collect::<Result<Vec<char>, NetErrors>>()?;
+            points.extend(chained_points.iter().cloned());
+            edges.extend(chained_points.windows(2).map(|pair| (pair[0], pair[1])));
+        }
+
+        let mut net = Net::from_edges(edges);
+        for point in points {
+            if net.nodes().iter().all(|node| !node.point_is(&point)) {
+                net.add_node(NodeBuilder::new().point(&point).build().map_err(NetErrors::InvalidFormat)?)?;
+            }
+        }
+
+        Ok(net)
+    }
+}
+
+fn quoted_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn without_attributes(body: &str) -> String {
+    let mut depth = 0;
+    body.chars()
+        .filter(|&character| {
+            match character {
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    return false;
+                },
+                _ => ()
+            }
+            depth == 0
+        })
+        .collect()
+}
+
+fn point_from_id(id: &str) -> Result<char, NetErrors> {
+    let id = id.trim().trim_matches('"');
+    let mut characters = id.chars();
+
+    match (characters.next(), characters.next()) {
+        (Some(point), None) => Ok(point),
+        _ => Err(NetErrors::InvalidFormat(format!(r#"cannot read a point from "{}""#, id)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use dot::*;
+    use node::NodeBuilder;
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn undirected_net_should_be_written_as_a_graph() {
+        let net = Net::from_edges(vec![('A', 'B'), ('B', 'C'), ('C', 'D'), ('D', 'A')]);
+
+        assert_eq!(net.to_dot(), "graph {\n    \"A\" -- \"B\";\n    \"A\" -- \"D\";\n    \"B\" -- \"C\";\n    \"C\" -- \"D\";\n}\n", "should write every edge once");
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn directed_net_should_be_written_as_a_digraph() {
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&'A').connected_point(&'B').build().unwrap(),
+            NodeBuilder::new().point(&'B').connected_point(&'A').directed_connected_point(&'C').build().unwrap(),
+            NodeBuilder::new().point(&'C').build().unwrap(),
+        ]);
+
+        assert_eq!(net.to_dot(), "digraph {\n    \"A\" -> \"B\";\n    \"B\" -> \"A\";\n    \"B\" -> \"C\";\n}\n", "should write every connection");
+    }
+
+    #[test]
+    fn net_read_from_dot_should_write_the_same_dot() {
+        let net = Net::from_dot("graph {\n    A -- B -- C [color=red];\n    \"C\" -- D;\n    E\n}").expect("should read the net");
+
+        let paths = net.find_paths(&'A', &'D').expect("should find paths from A to D");
+        assert_eq!(paths.iter().map(|path| path.to_string()).collect::<Vec<String>>(), vec!["A-B-C-D"], "should chain the edges");
+        assert_eq!(Net::from_dot(&net.to_dot()).expect("should read its own dot").to_dot(), net.to_dot(), "should read back the same net");
+        assert!(net.to_dot().contains("    \"E\";\n"), "should keep the isolated point");
+    }
+
+    #[test]
+    fn dot_with_points_longer_than_a_character_should_throw() {
+        match Net::from_dot("graph { AB -- C }") {
+            Err(NetErrors::InvalidFormat(_)) => (),
+            _ => panic!("InvalidFormat error expected")
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use net::Net;
+use net::NetErrors;
+use net::path_through_nodes;
+use net::sorted_paths_or_unreachable;
+use node::Node;
+use node::Point;
+use path::Path;
+use std::collections::HashMap;
+
+/// Read-only version of a `Net` laid out for fast traversal: the connections of every point
+/// are stored contiguously (CSR style) and points are resolved through an id index instead of
+/// scanning the node list. It only offers `find_paths` and `reachable_from`, which return the
+/// same as their `Net` counterparts. `thaw` turns it back into a `Net` for any other query or
+/// to edit it.
+#[derive(Debug)]
+pub struct FrozenNet<T: Point> {
+    nodes: Vec<Node<T>>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    positions: HashMap<T::Identifier, usize>,
+}
+
+impl<T: Point> FrozenNet<T> {
+    pub(crate) fn from_nodes(nodes: Vec<Node<T>>) -> Result<FrozenNet<T>, NetErrors> {
+        let positions: HashMap<T::Identifier, usize> = nodes.iter()
+            .enumerate()
+            .map(|(position, node)| (node.point().id().clone(), position))
+            .collect();
+
+        let mut offsets = vec![0];
+        let mut targets = Vec::new();
+        for node in &nodes {
+            for connected_point in node.connected_points() {
+                match positions.get(connected_point.id()) {
+                    Some(&position) => targets.push(position),
+                    None => return Err(NetErrors::PointNotFound(connected_point.id().to_string()))
+                }
+            }
+            offsets.push(targets.len());
+        }
+
+        Ok(FrozenNet { nodes, offsets, targets, positions })
+    }
+
+    pub fn thaw(self) -> Net<T> {
+        Net::new(self.nodes)
+    }
+
+    /// Same paths as `Net::find_paths`, in the same order.
+    pub fn find_paths(&self, origin: &T, destination: &T) -> Result<Vec<Path<T>>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        if origin_position == destination_position {
+            return Ok(vec![self.path_through_positions(&[origin_position])?]);
+        }
+
+        let mut in_path = vec![false; self.nodes.len()];
+        let mut current_path = vec![origin_position];
+        let mut pending_connections = vec![self.offsets[origin_position]];
+        in_path[origin_position] = true;
+        let mut paths = Vec::new();
+
+        while let Some(&position) = current_path.last() {
+            let next_connection = pending_connections.last_mut().unwrap();
+
+            if *next_connection == self.offsets[position + 1] {
+                in_path[position] = false;
+                current_path.pop();
+                pending_connections.pop();
+                continue;
+            }
+
+            let next_position = self.targets[*next_connection];
+            *next_connection += 1;
+
+            if in_path[next_position] {
+                continue;
+            }
+
+            if next_position == destination_position {
+                current_path.push(next_position);
+                paths.push(self.path_through_positions(&current_path)?);
+                current_path.pop();
+            } else {
+                in_path[next_position] = true;
+                current_path.push(next_position);
+                pending_connections.push(self.offsets[next_position]);
+            }
+        }
+
+        sorted_paths_or_unreachable(paths, origin, destination)
+    }
+
+    /// Same points as `Net::reachable_from`, in the same order.
+    pub fn reachable_from(&self, origin: &T) -> Result<Vec<&T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let mut visited = vec![false; self.nodes.len()];
+        visited[origin_position] = true;
+        let mut reached = vec![origin_position];
+        let mut next = 0;
+
+        while let Some(&position) = reached.get(next) {
+            next += 1;
+            for &next_position in &self.targets[self.offsets[position]..self.offsets[position + 1]] {
+                if !visited[next_position] {
+                    visited[next_position] = true;
+                    reached.push(next_position);
+                }
+            }
+        }
+
+        Ok(reached.into_iter()
+            .map(|position| self.nodes[position].point())
+            .collect())
+    }
+
+    fn path_through_positions(&self, positions: &[usize]) -> Result<Path<T>, NetErrors> {
+        path_through_nodes(positions.iter().map(|&position| &self.nodes[position]))
+    }
+
+    fn position_or_throws(&self, point: &T) -> Result<usize, NetErrors> {
+        self.positions.get(point.id())
+            .cloned()
+            .ok_or_else(|| NetErrors::PointNotFound(point.id().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use frozen::*;
+    use node::NodeBuilder;
+
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    struct SimplePoint {
+        name: char
+    }
+
+    impl Point for SimplePoint {
+        type Identifier = char;
+
+        fn id(&self) -> &char {
+            &self.name
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn frozen_net_should_find_the_same_paths_as_the_net() {
+        let point_a = simple_point('A');
+        let point_c = simple_point('C');
+        let net = diamond_net();
+
+        let net_paths = format_paths(net.find_paths(&point_a, &point_c).expect("should find paths in the net"));
+        let frozen_net = net.freeze().expect("should freeze a well formed net");
+        let frozen_paths = format_paths(frozen_net.find_paths(&point_a, &point_c).expect("should find paths in the frozen net"));
+
+        assert_eq!(frozen_paths, net_paths, "frozen net should find the same paths in the same order");
+    }
+
+    #[test]
+    fn frozen_net_should_find_the_trivial_path_from_a_point_to_itself() {
+        let point_a = simple_point('A');
+        let frozen_net = diamond_net().freeze().expect("should freeze a well formed net");
+
+        let paths = format_paths(frozen_net.find_paths(&point_a, &point_a).expect("should find the trivial path from A to A"));
+
+        assert_eq!(paths, vec!["A"], "should not go around a cycle");
+    }
+
+    #[test]
+    fn frozen_net_should_not_find_paths_to_an_unreachable_point() {
+        let point_a = simple_point('A');
+        let point_b = simple_point('B');
+        let frozen_net = Net::new(vec![node_connected_to(point_a, vec![]), node_connected_to(point_b, vec![])])
+            .freeze()
+            .expect("should freeze a well formed net");
+
+        match frozen_net.find_paths(&point_a, &point_b) {
+            Err(NetErrors::DestinationUnreachable(origin_id, destination_id)) => assert_eq!((origin_id, destination_id), (String::from("A"), String::from("B"))),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn frozen_net_should_reach_the_same_points_as_the_net() {
+        let point_b = simple_point('B');
+        let net = diamond_net();
+
+        let net_points: Vec<char> = net.reachable_from(&point_b).expect("should reach points in the net").iter().map(|point| point.name).collect();
+        let frozen_net = net.freeze().expect("should freeze a well formed net");
+        let frozen_points: Vec<char> = frozen_net.reachable_from(&point_b).expect("should reach points in the frozen net").iter().map(|point| point.name).collect();
+
+        assert_eq!(frozen_points, net_points, "frozen net should reach the same points in the same order");
+        assert_eq!(frozen_points, vec!['B', 'A', 'C', 'D'], "should go breadth first");
+    }
+
+    #[test]
+    fn thawed_net_should_find_the_same_paths_as_the_frozen_net() {
+        let point_a = simple_point('A');
+        let point_c = simple_point('C');
+        let frozen_net = diamond_net().freeze().expect("should freeze a well formed net");
+
+        let frozen_paths = format_paths(frozen_net.find_paths(&point_a, &point_c).expect("should find paths in the frozen net"));
+        let net = frozen_net.thaw();
+        let net_paths = format_paths(net.find_paths(&point_a, &point_c).expect("should find paths in the thawed net"));
+
+        assert_eq!(net_paths, frozen_paths, "thawing should keep every connection");
+    }
+
+    #[test]
+    fn freezing_a_net_with_a_connection_to_a_missing_point_should_fail() {
+        let point_a = simple_point('A');
+        let point_b = simple_point('B');
+        let net = Net::new(vec![node_connected_to(point_a, vec![point_b])]);
+
+        match net.freeze() {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "B"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    fn format_paths(paths: Vec<Path<SimplePoint>>) -> Vec<String> {
+        paths.iter()
+            .map(|path| format!("{}", path))
+            .collect()
+    }
+
+    fn diamond_net() -> Net<SimplePoint> {
+        let point_a = simple_point('A');
+        let point_b = simple_point('B');
+        let point_c = simple_point('C');
+        let point_d = simple_point('D');
+
+        Net::new(vec![
+            node_connected_to(point_a, vec![point_b, point_d]),
+            node_connected_to(point_b, vec![point_a, point_c, point_d]),
+            node_connected_to(point_c, vec![point_b, point_d]),
+            node_connected_to(point_d, vec![point_a, point_c, point_b]),
+        ])
+    }
+
+    fn simple_point(name: char) -> SimplePoint {
+        SimplePoint { name }
+    }
+
+    fn node_connected_to(point: SimplePoint, point_connected: Vec<SimplePoint>) -> Node<SimplePoint> {
+        NodeBuilder::new()
+            .point(&point)
+            .connected_points(&point_connected)
+            .build()
+            .unwrap()
+    }
+}
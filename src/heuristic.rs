@@ -0,0 +1,17 @@
+use node::Point;
+
+/// Estimate of the cost of going from one point to another, used to guide `Net::find_path_astar`.
+/// The estimate should never exceed the real cost for the search to return the cheapest path.
+pub trait Heuristic<T: Point> {
+    fn estimate(&self, from: &T, to: &T) -> f64;
+}
+
+/// Heuristic that always estimates zero, which makes an A* search behave like Dijkstra's.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZeroHeuristic;
+
+impl<T: Point> Heuristic<T> for ZeroHeuristic {
+    fn estimate(&self, _from: &T, _to: &T) -> f64 {
+        0.0
+    }
+}
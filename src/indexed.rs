@@ -0,0 +1,118 @@
+use net::NetErrors;
+
+/// Net whose points have been relabelled to `u32` indices, see `Net::to_indexed`. Searches
+/// work on plain indices, so they never compare or hash the original point ids.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedNet {
+    adjacency: Vec<Vec<u32>>
+}
+
+impl IndexedNet {
+    pub(crate) fn new(adjacency: Vec<Vec<u32>>) -> IndexedNet {
+        IndexedNet { adjacency }
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    pub fn neighbours(&self, point: u32) -> Result<&[u32], NetErrors> {
+        self.adjacency.get(point as usize)
+            .map(|neighbours| &neighbours[..])
+            .ok_or_else(|| NetErrors::PointNotFound(point.to_string()))
+    }
+
+    pub fn find_paths(&self, origin: u32, destination: u32) -> Result<Vec<Vec<u32>>, NetErrors> {
+        self.neighbours(origin)?;
+        self.neighbours(destination)?;
+        if origin == destination {
+            return Ok(vec![vec![origin]]);
+        }
+
+        let mut in_path = vec![false; self.adjacency.len()];
+        let mut current_path = vec![origin];
+        in_path[origin as usize] = true;
+        let mut paths = Vec::new();
+        self.collect_paths(destination, &mut current_path, &mut in_path, &mut paths);
+
+        if paths.is_empty() {
+            Err(NetErrors::DestinationUnreachable(origin.to_string(), destination.to_string()))
+        } else {
+            Ok(paths)
+        }
+    }
+
+    fn collect_paths(&self, destination: u32, current_path: &mut Vec<u32>, in_path: &mut [bool], paths: &mut Vec<Vec<u32>>) {
+        let last_point = *current_path.last().unwrap();
+
+        for &next_point in &self.adjacency[last_point as usize] {
+            if in_path[next_point as usize] {
+                continue;
+            }
+
+            current_path.push(next_point);
+            if next_point == destination {
+                paths.push(current_path.clone());
+            } else {
+                in_path[next_point as usize] = true;
+                self.collect_paths(destination, current_path, in_path, paths);
+                in_path[next_point as usize] = false;
+            }
+            current_path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use indexed::*;
+
+    // Given this net of points:
+    // 0 - 1 - 2
+    //  \  |  /
+    //   \ | /
+    //     3
+    #[test]
+    fn should_find_all_feasible_paths_from_0_to_2() {
+        let net = IndexedNet::new(vec![vec![1, 3], vec![0, 2, 3], vec![1, 3], vec![0, 2, 1]]);
+
+        let paths = net.find_paths(0, 2).expect("should not throw finding paths from 0 to 2");
+
+        assert_eq!(paths, vec![vec![0, 1, 2], vec![0, 1, 3, 2], vec![0, 3, 2], vec![0, 3, 1, 2]], "should find the four feasible paths");
+    }
+
+    #[test]
+    fn the_only_path_from_0_to_itself_should_be_0_alone() {
+        let net = IndexedNet::new(vec![vec![1, 3], vec![0, 2, 3], vec![1, 3], vec![0, 2, 1]]);
+
+        let paths = net.find_paths(0, 0).expect("should not throw finding paths from 0 to 0");
+
+        assert_eq!(paths, vec![vec![0]], "should not go around a cycle");
+    }
+
+    #[test]
+    fn find_paths_from_an_index_out_of_the_net_should_throw() {
+        let net = IndexedNet::new(vec![vec![1], vec![0]]);
+
+        match net.find_paths(2, 0) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "2"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points:
+    // 0 - 1   2
+    #[test]
+    fn find_paths_to_an_unreachable_index_should_tell_which_indices() {
+        let net = IndexedNet::new(vec![vec![1], vec![0], vec![]]);
+
+        match net.find_paths(0, 2) {
+            Err(NetErrors::DestinationUnreachable(origin_id, destination_id)) => assert_eq!((origin_id.as_str(), destination_id.as_str()), ("0", "2")),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+}
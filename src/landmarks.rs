@@ -0,0 +1,134 @@
+use heuristic::Heuristic;
+use net::Net;
+use net::NetErrors;
+use node::Point;
+use path::Path;
+use std::collections::HashMap;
+
+/// Points of a net along with the cost of the cheapest routes from and to each of them, see
+/// `Net::build_landmarks`. Estimates built from them never exceed the real cost of a route.
+#[derive(Debug)]
+pub struct Landmarks<T: Point> {
+    points: Vec<T>,
+    costs_from: Vec<HashMap<T::Identifier, f64>>,
+    costs_to: Vec<HashMap<T::Identifier, f64>>,
+}
+
+impl<T: Point> Landmarks<T> {
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+}
+
+impl<T: Point> Heuristic<T> for Landmarks<T> {
+    /// Largest bound given by the triangle inequality over every landmark reaching, or reached
+    /// from, both points.
+    fn estimate(&self, from: &T, to: &T) -> f64 {
+        let (from_id, to_id) = (from.id(), to.id());
+        let mut estimate: f64 = 0.0;
+
+        for (costs_from, costs_to) in self.costs_from.iter().zip(&self.costs_to) {
+            if let (Some(cost_to_from), Some(cost_to_to)) = (costs_from.get(from_id), costs_from.get(to_id)) {
+                estimate = estimate.max(cost_to_to - cost_to_from);
+            }
+            if let (Some(cost_from_from), Some(cost_from_to)) = (costs_to.get(from_id), costs_to.get(to_id)) {
+                estimate = estimate.max(cost_from_from - cost_from_to);
+            }
+        }
+
+        estimate
+    }
+}
+
+impl<T: Point> Net<T> {
+    /// Picks up to `count` landmarks, each one as far as possible from the ones already picked,
+    /// starting with the first point of the net, and precomputes the costs from and to them.
+    pub fn build_landmarks(&self, count: usize) -> Result<Landmarks<T>, NetErrors> {
+        let mut landmarks = Landmarks { points: Vec::new(), costs_from: Vec::new(), costs_to: Vec::new() };
+        let mut is_landmark = vec![false; self.nodes().len()];
+        let mut closest_landmark_costs: Vec<Option<f64>> = vec![None; self.nodes().len()];
+
+        while landmarks.points.len() < count {
+            let position = match farthest_position(&closest_landmark_costs, &is_landmark) {
+                Some(position) => position,
+                None => break
+            };
+            let costs_from = self.cheapest_costs_from(position, false)?;
+            let costs_to = self.cheapest_costs_from(position, true)?;
+
+            for (closest_cost, &cost) in closest_landmark_costs.iter_mut().zip(&costs_from) {
+                *closest_cost = match (*closest_cost, cost) {
+                    (Some(closest), Some(cost)) => Some(closest.min(cost)),
+                    (None, cost) => cost,
+                    (closest, None) => closest
+                };
+            }
+            is_landmark[position] = true;
+            landmarks.points.push(self.nodes()[position].point().clone());
+            landmarks.costs_from.push(self.costs_by_id(&costs_from));
+            landmarks.costs_to.push(self.costs_by_id(&costs_to));
+        }
+
+        Ok(landmarks)
+    }
+
+    /// Finds the path with the lowest total weight using A*, guided by the costs precomputed in
+    /// `landmarks`. The path has the same cost as the one `shortest_path` finds.
+    pub fn shortest_path_alt(&self, origin: &T, destination: &T, landmarks: &Landmarks<T>) -> Result<Path<T>, NetErrors> {
+        self.find_path_astar(origin, destination, landmarks)
+    }
+
+    fn costs_by_id(&self, costs: &[Option<f64>]) -> HashMap<T::Identifier, f64> {
+        self.nodes().iter()
+            .zip(costs)
+            .filter_map(|(node, cost)| cost.map(|cost| (node.point().id().clone(), cost)))
+            .collect()
+    }
+}
+
+/// Position that is not a landmark yet and lies the farthest from every landmark, points that no
+/// landmark reaches coming first. Ties go to the point added to the net first.
+fn farthest_position(closest_landmark_costs: &[Option<f64>], is_landmark: &[bool]) -> Option<usize> {
+    let mut farthest: Option<(usize, f64)> = None;
+    for (position, closest_cost) in closest_landmark_costs.iter().enumerate() {
+        let cost = closest_cost.unwrap_or(f64::INFINITY);
+        if !is_landmark[position] && farthest.is_none_or(|(_, farthest_cost)| cost > farthest_cost) {
+            farthest = Some((position, cost));
+        }
+    }
+
+    farthest.map(|(position, _)| position)
+}
+
+#[cfg(test)]
+mod test {
+    use landmarks::*;
+    use node::NodeBuilder;
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A -1- B -1> C
+    // |           |
+    // 2           1
+    // |           |
+    // D ----5---- E
+    #[test]
+    fn alt_should_find_paths_as_cheap_as_dijkstra() {
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&'A').connected_point_with_weight(&'B', 1.0).connected_point_with_weight(&'D', 2.0).build().unwrap(),
+            NodeBuilder::new().point(&'B').connected_point_with_weight(&'A', 1.0).directed_connected_point_with_weight(&'C', 1.0).build().unwrap(),
+            NodeBuilder::new().point(&'C').connected_point_with_weight(&'E', 1.0).build().unwrap(),
+            NodeBuilder::new().point(&'D').connected_point_with_weight(&'A', 2.0).connected_point_with_weight(&'E', 5.0).build().unwrap(),
+            NodeBuilder::new().point(&'E').connected_point_with_weight(&'C', 1.0).connected_point_with_weight(&'D', 5.0).build().unwrap(),
+        ]);
+        let landmarks = net.build_landmarks(2).expect("should pick two landmarks");
+
+        for origin in &['A', 'B', 'C', 'D', 'E'] {
+            for destination in &['A', 'B', 'C', 'D', 'E'] {
+                let dijkstra_cost = net.shortest_path(origin, destination).map(|path| path.cost()).ok();
+                let alt_cost = net.shortest_path_alt(origin, destination, &landmarks).map(|path| path.cost()).ok();
+                assert_eq!(alt_cost, dijkstra_cost, "should cost the same from {} to {}", origin, destination);
+            }
+        }
+        assert_eq!(landmarks.points(), &['A', 'E'], "should start with A and pick E, the farthest from it");
+    }
+}
@@ -1,6 +1,18 @@
 #[macro_use]
 extern crate quick_error;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 
 pub mod node;
 pub mod path;
 pub mod net;
+pub mod frozen;
+pub mod indexed;
+pub mod heuristic;
+pub mod landmarks;
+pub mod dot;
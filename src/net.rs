@@ -1,303 +1,5586 @@
+use frozen::FrozenNet;
+use heuristic::Heuristic;
+use heuristic::ZeroHeuristic;
+use indexed::IndexedNet;
 use node::Node;
+use node::NodeBuilder;
 use node::Point;
 use path::PathBuilder;
 use path::Path;
+use path::Walk;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::ops::ControlFlow;
+#[cfg(feature = "serde")]
+use std::str::FromStr;
 
+/// Points and the connections between them. A net keeps an index of its points next to its
+/// nodes, so it cannot be written as a struct literal: build it with `Net::new`,
+/// `Net::from_edges` or `NetBuilder` instead. Deserializing a net also goes through `Net::new`.
+/// Nodes are read with `Net::nodes` and only changed through the methods of the net.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SerializedNet<T>"))]
 pub struct Net<T: Point> {
-    pub nodes: Vec<Node<T>>
+    nodes: Vec<Node<T>>,
+    /// Position in `nodes` of every point id.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    positions: HashMap<T::Identifier, usize>,
+    /// Bumped by every method that changes the net.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    version: u64,
+    /// Version of the net the paths in `path_cache` were found in.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cached_version: u64,
+    /// Paths found by `find_paths_cached`, by ids of their origin and destination.
+    #[cfg_attr(feature = "serde", serde(skip, default = "HashMap::new"))]
+    path_cache: PathCache<T>
 }
 
-impl<'a, T: Point> Net<T> {
-    pub fn find_paths(&self, origin: &'a T, destination: &'a T) -> Result<Vec<Path<T>>, NetErrors> {
+/// Fields of a net as they are serialized, turned into a net with `Net::new` so that its index
+/// is built.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SerializedNet<T: Point> {
+    nodes: Vec<Node<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Point> From<SerializedNet<T>> for Net<T> {
+    fn from(serialized_net: SerializedNet<T>) -> Net<T> {
+        Net::new(serialized_net.nodes)
+    }
+}
+
+impl<T: Point> Net<T> {
+    pub fn new(nodes: Vec<Node<T>>) -> Net<T> {
+        let mut net = Net { nodes, positions: HashMap::new(), version: 0, cached_version: 0, path_cache: HashMap::new() };
+        net.reindex();
+        net.resolve_connected_ids();
+
+        net
+    }
+
+    /// Builds a net with one node per distinct point, connecting both ends of every edge.
+    /// Repeated edges are collapsed and edges from a point to itself are ignored.
+    pub fn from_edges(edges: impl IntoIterator<Item = (T, T)>) -> Net<T> {
+        let mut net = Net::new(Vec::new());
+
+        for (a, b) in edges {
+            let a_position = net.position_or_insert(&a);
+            let b_position = net.position_or_insert(&b);
+            if a_position != b_position {
+                net.nodes[a_position].connect_to(&b);
+                net.nodes[b_position].connect_to(&a);
+            }
+        }
+
+        net
+    }
+
+    /// Every simple path from `origin` to `destination`, sorted by number of hops and then by
+    /// the ids of their points. Fails with `DestinationUnreachable` if both points are in the
+    /// net but no route connects them. The only path from a point to itself is the path made
+    /// of just that point.
+    pub fn find_paths(&self, origin: &T, destination: &T) -> Result<Vec<Path<T>>, NetErrors> {
+        self.find_paths_with_max_length(origin, destination, usize::MAX)
+    }
+
+    /// Same as `find_paths`, but remembers the paths found until the net changes, so asking
+    /// again for the same points does not search the net again. Errors are not remembered.
+    pub fn find_paths_cached(&mut self, origin: &T, destination: &T) -> Result<Vec<Path<T>>, NetErrors> {
+        if self.cached_version != self.version {
+            self.path_cache.clear();
+            self.cached_version = self.version;
+        }
+
+        let key = (origin.id().clone(), destination.id().clone());
+        if let Some(paths) = self.path_cache.get(&key) {
+            return Ok(paths.clone());
+        }
+
+        let paths = self.find_paths(origin, destination)?;
+        self.path_cache.insert(key, paths.clone());
+        Ok(paths)
+    }
+
+    /// Number of changes made to the net through its methods so far.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Nodes of the net, in the order their points were added. They can only be changed through
+    /// the methods of the net, which keep its index and version up to date.
+    pub fn nodes(&self) -> &[Node<T>] {
+        &self.nodes
+    }
+
+    /// Same paths as `find_paths`, in the same order, found the way `options` says.
+    pub fn find_paths_with_options(&self, origin: &T, destination: &T, options: &SearchOptions) -> Result<Vec<Path<T>>, NetErrors> {
+        if !options.iterative {
+            return self.find_paths(origin, destination);
+        }
+
+        sorted_paths_or_unreachable(self.iter_paths(origin, destination)?.collect(), origin, destination)
+    }
+
+    /// Every walk from `origin` to `destination` made of at most `max_length` points. Unlike
+    /// `find_paths`, walks may go through the same point several times, even through the
+    /// destination before ending there, which is why the length has to be bounded. Walks are
+    /// sorted by length and then by the ids of their points. Fails with `NoPathFound` if no walk
+    /// is short enough, or with `DestinationUnreachable` if there is no route at all.
+    pub fn find_walks(&self, origin: &T, destination: &T, max_length: usize) -> Result<Vec<Walk<T>>, NetErrors> {
+        self.find_walks_with_options(origin, destination, max_length, &SearchOptions::new())
+    }
+
+    /// Same as `find_walks`, but only goes through the destination before ending there when
+    /// `options` allows it, see `SearchOptions::allow_passthrough_destination`.
+    pub fn find_walks_with_options(&self, origin: &T, destination: &T, max_length: usize, options: &SearchOptions) -> Result<Vec<Walk<T>>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let (connections, _) = self.weighted_connections()?;
+
+        let mut walks = Vec::new();
+        if origin_position == destination_position && max_length > 0 {
+            walks.push(Walk::new(vec![origin.clone()], 0.0));
+        }
+
+        let mut route = vec![(origin_position, 0.0)];
+        let mut pending = if origin_position == destination_position && !options.allow_passthrough_destination {
+            Vec::new()
+        } else {
+            vec![0]
+        };
+        while let Some(next_connection) = pending.pop() {
+            let (position, cost) = route[route.len() - 1];
+            match connections[position].get(next_connection).filter(|_| route.len() < max_length) {
+                Some(&(next_position, weight)) => {
+                    pending.push(next_connection + 1);
+                    route.push((next_position, cost + weight));
+                    if next_position == destination_position {
+                        let points = route.iter().map(|&(position, _)| self.nodes[position].point().clone()).collect();
+                        walks.push(Walk::new(points, cost + weight));
+                        if !options.allow_passthrough_destination {
+                            route.pop();
+                            continue;
+                        }
+                    }
+                    pending.push(0);
+                },
+                None => {
+                    route.pop();
+                }
+            }
+        }
+
+        if walks.is_empty() {
+            return Err(self.missed_path_error(origin, destination));
+        }
+        walks.sort_by(Walk::cmp_by_length_then_ids);
+
+        Ok(walks)
+    }
+
+    /// Same as `find_paths`, in the same order, but only keeps the paths made of at most
+    /// `max_hops` points: the search stops following a route as soon as it reaches that length.
+    /// Fails with `NoPathFound` if no route is short enough, or with `DestinationUnreachable` if
+    /// there is no route at all.
+    pub fn find_paths_with_max_length(&self, origin: &T, destination: &T, max_hops: usize) -> Result<Vec<Path<T>>, NetErrors> {
         let node_from = self.find_node_or_throws(origin)?;
+        self.position_or_throws(destination)?;
 
         let path_starting_with_origin_point = PathBuilder::new().point(origin).build();
 
         match path_starting_with_origin_point {
             Err(message) => Err(NetErrors::PathCannotBeBuilt(message)),
-            Ok(beginning_path) => match self.find_paths_not_crossing_previous_path(&node_from, &destination, &beginning_path) {
-                Some(paths) => Ok(paths),
-                None => Err(NetErrors::NoPathFound)
+            Ok(beginning_path) if origin.is(destination) => if max_hops > 0 {
+                Ok(vec![beginning_path])
+            } else {
+                Err(NetErrors::NoPathFound)
+            },
+            Ok(beginning_path) => match self.find_paths_not_crossing_previous_path(node_from, destination, &beginning_path, max_hops)? {
+                Some(paths) => sorted_paths_or_unreachable(paths, origin, destination),
+                None => Err(self.missed_path_error(origin, destination))
+            }
+        }
+    }
+
+    /// Paths of `find_paths` going through every waypoint, in any order.
+    pub fn find_paths_through(&self, origin: &T, destination: &T, waypoints: &[&T]) -> Result<Vec<Path<T>>, NetErrors> {
+        for waypoint in waypoints {
+            self.position_or_throws(waypoint)?;
+        }
+
+        let paths: Vec<Path<T>> = self.find_paths(origin, destination)?
+            .into_iter()
+            .filter(|path| waypoints.iter().all(|waypoint| !path.do_not_contains(waypoint)))
+            .collect();
+
+        if paths.is_empty() {
+            Err(NetErrors::NoPathFound)
+        } else {
+            Ok(paths)
+        }
+    }
+
+    /// Checks that no two nodes share a point id, that every connection leads to a node of the
+    /// net and that every id given to `NodeBuilder::connected_id` matches one.
+    pub fn validate(&self) -> Result<(), NetErrors> {
+        for (position, node) in self.nodes.iter().enumerate() {
+            if self.nodes[..position].iter().any(|previous_node| previous_node.point_is(node.point())) {
+                return Err(NetErrors::DuplicatePoint(node.point().id().to_string()));
+            }
+
+            for connected_point in node.connected_points() {
+                if !self.nodes.iter().any(|other_node| other_node.point_is(connected_point)) {
+                    return Err(NetErrors::DanglingConnection(node.point().id().to_string(), connected_point.id().to_string()));
+                }
+            }
+
+            if let Some(id) = node.connected_ids().first() {
+                return Err(NetErrors::PointNotFound(id.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the node of every point reached through an undirected connection connects
+    /// back, failing with the first connection that does not. One-way connections are skipped.
+    pub fn assert_symmetric(&self) -> Result<(), NetErrors> {
+        for node in &self.nodes {
+            for connected_point in node.connected_points() {
+                if node.is_directed_to(connected_point) {
+                    continue;
+                }
+
+                let connected_node = self.find_node_or_throws(connected_point)
+                    .map_err(|_| NetErrors::DanglingConnection(node.point().id().to_string(), connected_point.id().to_string()))?;
+                if !connected_node.is_connected_to(node.point()) {
+                    return Err(NetErrors::AsymmetricConnection(node.point().id().to_string(), connected_point.id().to_string()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the connections missing for `assert_symmetric` to pass, each one weighing as much as
+    /// the connection it mirrors. Connections to points that are not in the net are left alone.
+    pub fn symmetrize(&mut self) {
+        let mut missing_connections = Vec::new();
+        for node in &self.nodes {
+            for (connected_point, weight) in node.weighted_connected_points() {
+                if node.is_directed_to(connected_point) {
+                    continue;
+                }
+
+                if let Ok(position) = self.position_or_throws(connected_point) {
+                    if !self.nodes[position].is_connected_to(node.point()) {
+                        missing_connections.push((position, node.point().clone(), weight));
+                    }
+                }
+            }
+        }
+
+        for (position, point, weight) in missing_connections {
+            self.nodes[position].connect_to_with_weight(&point, weight);
+        }
+        self.touch();
+    }
+
+    /// Adds the nodes of `other` to the net. Points in both nets keep their node here and gain
+    /// the connections of `other` to points they were not connected to yet. When both nets
+    /// connect the same pair of points, the connection of this net wins, weight and direction.
+    pub fn merge(&mut self, other: Net<T>) {
+        for node in other.nodes {
+            match self.position_or_throws(node.point()) {
+                Ok(position) => self.nodes[position].merge_connections(node),
+                Err(_) => {
+                    self.positions.insert(node.point().id().clone(), self.nodes.len());
+                    self.nodes.push(node);
+                }
+            }
+        }
+        self.resolve_connected_ids();
+        self.touch();
+    }
+
+    /// Fails with `DuplicatePoint` if the net already has a node for the same point.
+    pub fn add_node(&mut self, node: Node<T>) -> Result<(), NetErrors> {
+        if self.nodes.iter().any(|other_node| other_node.point_is(node.point())) {
+            return Err(NetErrors::DuplicatePoint(node.point().id().to_string()));
+        }
+
+        self.positions.insert(node.point().id().clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.resolve_connected_ids();
+        self.touch();
+        Ok(())
+    }
+
+    /// Removes the node of `point` along with every connection leading to it.
+    pub fn remove_point(&mut self, point: &T) -> Result<Node<T>, NetErrors> {
+        let position = self.position_or_throws(point)?;
+        let removed_node = self.nodes.remove(position);
+
+        for node in &mut self.nodes {
+            node.disconnect_from(point);
+        }
+        self.reindex();
+        self.touch();
+
+        Ok(removed_node)
+    }
+
+    /// Connects both points to each other. Connecting points that already are does nothing.
+    pub fn connect(&mut self, a: &T, b: &T) -> Result<(), NetErrors> {
+        let a_position = self.position_or_throws(a)?;
+        let b_position = self.position_or_throws(b)?;
+        if a_position == b_position {
+            return Err(NetErrors::NodeCannotBeBuilt(String::from("Point cannot be connected to itself")));
+        }
+
+        self.nodes[a_position].connect_to(b);
+        self.nodes[b_position].connect_to(a);
+        self.touch();
+        Ok(())
+    }
+
+    /// Removes the connections between both points, in both directions.
+    pub fn disconnect(&mut self, a: &T, b: &T) -> Result<(), NetErrors> {
+        let a_position = self.position_or_throws(a)?;
+        let b_position = self.position_or_throws(b)?;
+
+        self.nodes[a_position].disconnect_from(b);
+        self.nodes[b_position].disconnect_from(a);
+        self.touch();
+        Ok(())
+    }
+
+    /// Turns the net into a read-only `FrozenNet` optimised for running many queries. Fails if a
+    /// connection points to a point that has no node in the net.
+    pub fn freeze(self) -> Result<FrozenNet<T>, NetErrors> {
+        FrozenNet::from_nodes(self.nodes)
+    }
+
+    /// Relabels every point to its position in `nodes` and returns the resulting `IndexedNet`
+    /// along with the ids of the points, so that `ids[index]` recovers the original point id.
+    pub fn to_indexed(&self) -> Result<(IndexedNet, Vec<T::Identifier>), NetErrors> {
+        let ids: Vec<T::Identifier> = self.nodes.iter()
+            .map(|node| node.point().id().clone())
+            .collect();
+
+        let indices: HashMap<T::Identifier, u32> = self.nodes.iter()
+            .enumerate()
+            .map(|(index, node)| (node.point().id().clone(), index as u32))
+            .collect();
+
+        let mut adjacency = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let mut neighbours = Vec::new();
+            for connected_point in node.connected_points() {
+                match indices.get(connected_point.id()) {
+                    Some(&index) => neighbours.push(index),
+                    None => return Err(NetErrors::PointNotFound(connected_point.id().to_string()))
+                }
+            }
+            adjacency.push(neighbours);
+        }
+
+        Ok((IndexedNet::new(adjacency), ids))
+    }
+
+    /// Number of paths `find_paths` would return, counted without building any of them. Points
+    /// that no route connects have zero paths between them.
+    pub fn count_paths(&self, origin: &T, destination: &T) -> Result<usize, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        if origin_position == destination_position {
+            return Ok(1);
+        }
+        let (connections, _) = self.weighted_connections()?;
+
+        let mut in_path = vec![false; self.nodes.len()];
+        in_path[origin_position] = true;
+        let mut pending = vec![(origin_position, 0)];
+        let mut count = 0;
+
+        while let Some((position, next_connection)) = pending.pop() {
+            match connections[position].get(next_connection) {
+                Some(&(next_position, _)) => {
+                    pending.push((position, next_connection + 1));
+                    if next_position == destination_position {
+                        count += 1;
+                    } else if !in_path[next_position] {
+                        in_path[next_position] = true;
+                        pending.push((next_position, 0));
+                    }
+                },
+                None => in_path[position] = false
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// First `limit` paths yielded by `iter_paths`, so a subset of what `find_paths` returns,
+    /// sorted the same way. The search stops as soon as enough paths have been found, so they
+    /// are not necessarily the shortest ones. A limit of zero finds no path.
+    pub fn find_some_paths(&self, origin: &T, destination: &T, limit: usize) -> Result<Vec<Path<T>>, NetErrors> {
+        let paths: Vec<Path<T>> = self.iter_paths(origin, destination)?
+            .take(limit)
+            .collect();
+
+        if limit == 0 {
+            Ok(paths)
+        } else {
+            sorted_paths_or_unreachable(paths, origin, destination)
+        }
+    }
+
+    /// Same paths as `find_paths`, in the same order, but fails with `TooManyPaths` as soon as
+    /// more than `hard_cap` paths are found instead of enumerating all of them.
+    pub fn find_paths_capped(&self, origin: &T, destination: &T, hard_cap: usize) -> Result<Vec<Path<T>>, NetErrors> {
+        let paths: Vec<Path<T>> = self.iter_paths(origin, destination)?
+            .take(hard_cap.saturating_add(1))
+            .collect();
+
+        if paths.len() > hard_cap {
+            return Err(NetErrors::TooManyPaths(hard_cap));
+        }
+
+        sorted_paths_or_unreachable(paths, origin, destination)
+    }
+
+    /// Lazily yields the same simple paths as `find_paths`, one at a time and in traversal order
+    /// rather than sorted, so that callers can stop early without enumerating every path.
+    /// Connections are resolved when the iterator is created, failing with `PointNotFound` if any
+    /// of them points outside the net.
+    pub fn iter_paths(&self, origin: &T, destination: &T) -> Result<PathIterator<'_, T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        self.iter_paths_restricted(origin_position, destination_position, &[], |_, _| true)
+    }
+
+    /// Lazily yields the simple paths from `origin` to `destination` along with their cost, the
+    /// cheapest first, by always extending the cheapest partial path queued so far. Costs only
+    /// come out in nondecreasing order when no weight is negative. Every partial path reached
+    /// stays queued, so memory grows with the number of paths explored.
+    pub fn iter_paths_by_cost(&self, origin: &T, destination: &T) -> Result<PathsByCost<'_, T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let (connections, _) = self.weighted_connections()?;
+
+        let mut pending = BinaryHeap::new();
+        pending.push(PartialPath { cost: 0.0, positions: vec![origin_position] });
+
+        Ok(PathsByCost { net: self, connections, destination_position, pending })
+    }
+
+    /// Calls `visitor` with every path `iter_paths` yields, as soon as it is found, without
+    /// keeping any of them. Stops as soon as `visitor` breaks. Finding no path is not an error,
+    /// `visitor` is just never called.
+    pub fn for_each_path<F: FnMut(&Path<T>) -> ControlFlow<()>>(&self, origin: &T, destination: &T, mut visitor: F) -> Result<(), NetErrors> {
+        for path in self.iter_paths(origin, destination)? {
+            if visitor(&path).is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Same paths as `find_paths`, in the same order, except the ones going through a blocked
+    /// point. Fails with `BlockedPoint` if the origin or the destination is blocked.
+    pub fn find_paths_avoiding(&self, origin: &T, destination: &T, blocked: &[&T]) -> Result<Vec<Path<T>>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let blocked_positions = blocked.iter()
+            .map(|blocked_point| self.position_or_throws(blocked_point))
+            .collect::<Result<Vec<usize>, NetErrors>>()?;
+
+        for &endpoint in &[origin, destination] {
+            if blocked.iter().any(|blocked_point| blocked_point.is(endpoint)) {
+                return Err(NetErrors::BlockedPoint(endpoint.id().to_string()));
+            }
+        }
+
+        let paths = self.iter_paths_restricted(origin_position, destination_position, &blocked_positions, |_, _| true)?;
+        sorted_paths_or_unreachable(paths.collect(), origin, destination)
+    }
+
+    /// Same paths as `find_paths`, in the same order, but only following a connection from `u`
+    /// to `v` when `edge_ok(u, v)` is true. `edge_ok` is called once per connection.
+    pub fn find_paths_where<F: Fn(&T, &T) -> bool>(&self, origin: &T, destination: &T, edge_ok: F) -> Result<Vec<Path<T>>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+
+        let paths = self.iter_paths_restricted(origin_position, destination_position, &[], edge_ok)?;
+        sorted_paths_or_unreachable(paths.collect(), origin, destination)
+    }
+
+    /// Same as `find_paths`, but gives up after extending partial paths `max_nodes_expanded`
+    /// times, failing with `SearchBudgetExceeded` and the ids of the paths found until then.
+    pub fn find_paths_with_budget(&self, origin: &T, destination: &T, max_nodes_expanded: usize) -> Result<Vec<Path<T>>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+
+        let mut path_iterator = self.iter_paths_restricted(origin_position, destination_position, &[], |_, _| true)?;
+        path_iterator.expansions_left = max_nodes_expanded;
+        let paths: Vec<Path<T>> = path_iterator.by_ref().collect();
+
+        if path_iterator.budget_exceeded {
+            let paths_found = paths.iter()
+                .map(|path| path.points().iter().map(|point| point.id().to_string()).collect())
+                .collect();
+            return Err(NetErrors::SearchBudgetExceeded(paths_found));
+        }
+
+        sorted_paths_or_unreachable(paths, origin, destination)
+    }
+
+    fn iter_paths_restricted<F: Fn(&T, &T) -> bool>(&self, origin_position: usize, destination_position: usize, blocked_positions: &[usize], edge_ok: F) -> Result<PathIterator<'_, T>, NetErrors> {
+        let mut connections = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let mut next_positions = Vec::new();
+            for connected_point in node.connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                if edge_ok(node.point(), connected_point) {
+                    next_positions.push(next_position);
+                }
+            }
+            connections.push(next_positions);
+        }
+
+        let mut in_path = vec![false; self.nodes.len()];
+        for &blocked_position in blocked_positions {
+            in_path[blocked_position] = true;
+        }
+        in_path[origin_position] = true;
+
+        Ok(PathIterator {
+            net: self,
+            connections,
+            destination_position,
+            in_path,
+            current_path: vec![origin_position],
+            next_connections: vec![0],
+            expansions_left: usize::MAX,
+            budget_exceeded: false,
+            trivial_path_pending: origin_position == destination_position,
+        })
+    }
+
+    /// Finds the path with the lowest total weight, preferring the one with fewer hops when
+    /// several paths have the same cost. It goes through every path `find_paths` would return.
+    pub fn find_cheapest_path(&self, origin: &T, destination: &T) -> Result<Path<T>, NetErrors> {
+        let paths = self.find_paths(origin, destination)?;
+
+        paths.into_iter()
+            .min_by(|path, other_path| path.cost().partial_cmp(&other_path.cost())
+                .unwrap_or(Ordering::Equal)
+                .then(path.hops().cmp(&other_path.hops())))
+            .ok_or(NetErrors::NoPathFound)
+    }
+
+    /// Total weight of the connections of this net followed by `path`, whatever the weights the
+    /// path carries. Fails with `NotAnEdge` if two consecutive points are not connected.
+    pub fn path_cost(&self, path: &Path<T>) -> Result<f64, NetErrors> {
+        let mut cost = 0.0;
+        for hop in path.points().windows(2) {
+            cost += self.find_node_or_throws(&hop[0])?.weight_to(&hop[1])
+                .ok_or_else(|| NetErrors::NotAnEdge(hop[0].id().to_string(), hop[1].id().to_string()))?;
+        }
+
+        Ok(cost)
+    }
+
+    /// Finds the path with the lowest total weight using Dijkstra's algorithm, which unlike
+    /// `find_cheapest_path` does not go through every path. Ties are broken by number of hops.
+    /// Weights are expected to be non-negative.
+    pub fn shortest_path(&self, origin: &T, destination: &T) -> Result<Path<T>, NetErrors> {
+        self.find_path_astar(origin, destination, &ZeroHeuristic)
+    }
+
+    /// Finds the path with the fewest hops using a breadth-first search, ignoring the weights.
+    pub fn shortest_hop_path(&self, origin: &T, destination: &T) -> Result<Path<T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let hop_distances = self.hop_distances_from(origin_position)?;
+
+        if hop_distances.distances[destination_position].is_none() {
+            return Err(unreachable_error(origin, destination));
+        }
+
+        self.path_from_previous_positions(&hop_distances.previous, destination_position)
+    }
+
+    /// Finds the path with the lowest total weight using A*, guided by `heuristic`. The result is
+    /// the cheapest path as long as the heuristic never overestimates the remaining cost.
+    pub fn find_path_astar<H: Heuristic<T>>(&self, origin: &T, destination: &T, heuristic: &H) -> Result<Path<T>, NetErrors> {
+        self.find_path_astar_with_node_cost(origin, destination, heuristic, |_| 0.0)
+            .map(|(path, _, _)| path)
+    }
+
+    /// Finds the cheapest path when going through a point costs `node_cost` on top of the weight
+    /// of the connections, along with its total cost. Only intermediate points are charged, not
+    /// the origin nor the destination. Costs and weights are expected to be non-negative.
+    pub fn find_cheapest_path_with_node_cost<F: Fn(&T) -> f64>(&self, origin: &T, destination: &T, node_cost: F) -> Result<(Path<T>, f64), NetErrors> {
+        self.find_path_astar_with_node_cost(origin, destination, &ZeroHeuristic, node_cost)
+            .map(|(path, cost, _)| (path, cost))
+    }
+
+    /// Number of points `shortest_path` expands before reaching `destination`, to compare the
+    /// work it does with `shortest_path_bidirectional_expansions`.
+    pub fn shortest_path_expansions(&self, origin: &T, destination: &T) -> Result<usize, NetErrors> {
+        self.find_path_astar_with_node_cost(origin, destination, &ZeroHeuristic, |_| 0.0)
+            .map(|(_, _, expansions)| expansions)
+    }
+
+    /// Returns the path along with its cost and the number of points expanded to find it.
+    fn find_path_astar_with_node_cost<H: Heuristic<T>, F: Fn(&T) -> f64>(&self, origin: &T, destination: &T, heuristic: &H, node_cost: F) -> Result<(Path<T>, f64, usize), NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let priority = |cost: f64, position: usize| cost + heuristic.estimate(self.nodes[position].point(), destination);
+
+        let mut best: Vec<Option<(f64, usize)>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut frontier = BinaryHeap::new();
+        best[origin_position] = Some((0.0, 0));
+        frontier.push(Candidate { priority: priority(0.0, origin_position), cost: 0.0, hops: 0, position: origin_position });
+        let mut expansions = 0;
+
+        while let Some(Candidate { cost, hops, position, .. }) = frontier.pop() {
+            if best[position] != Some((cost, hops)) {
+                continue;
+            }
+
+            if position == destination_position {
+                return Ok((self.path_from_previous_positions(&previous, destination_position)?, cost, expansions));
+            }
+
+            expansions += 1;
+
+            for (connected_point, weight) in self.nodes[position].weighted_connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                let passing_cost = if next_position == destination_position { 0.0 } else { node_cost(connected_point) };
+                let next_cost = cost + weight + passing_cost;
+                let candidate = Candidate { priority: priority(next_cost, next_position), cost: next_cost, hops: hops + 1, position: next_position };
+
+                if best[next_position].is_none_or(|current| candidate.is_cheaper_than(current)) {
+                    best[next_position] = Some((candidate.cost, candidate.hops));
+                    previous[next_position] = Some(position);
+                    frontier.push(candidate);
+                }
+            }
+        }
+
+        Err(unreachable_error(origin, destination))
+    }
+
+    /// Finds a path quickly with a greedy best-first search, always expanding the point that
+    /// `heuristic` estimates to be the closest to the destination and ignoring the cost of the
+    /// route so far. The path is not necessarily the cheapest. On reaching a dead end the search
+    /// goes on from the next most promising point, so a path is found whenever there is one.
+    pub fn find_path_greedy<H: Heuristic<T>>(&self, origin: &T, destination: &T, heuristic: &H) -> Result<Path<T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let candidate = |hops: usize, position: usize| Candidate {
+            priority: heuristic.estimate(self.nodes[position].point(), destination),
+            cost: 0.0,
+            hops,
+            position
+        };
+
+        let mut discovered = vec![false; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut frontier = BinaryHeap::new();
+        discovered[origin_position] = true;
+        frontier.push(candidate(0, origin_position));
+
+        while let Some(Candidate { hops, position, .. }) = frontier.pop() {
+            if position == destination_position {
+                return self.path_from_previous_positions(&previous, destination_position);
+            }
+
+            for connected_point in self.nodes[position].connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                if !discovered[next_position] {
+                    discovered[next_position] = true;
+                    previous[next_position] = Some(position);
+                    frontier.push(candidate(hops + 1, next_position));
+                }
+            }
+        }
+
+        Err(unreachable_error(origin, destination))
+    }
+
+    /// Draws a path at random with a depth-first walk that, from each point, tries its
+    /// connections in an order shuffled with `seed`, backing up from dead ends. The same seed
+    /// always gives the same path. Paths are not drawn uniformly: the walk is more likely to go
+    /// through points with few connections, since each connection of a busy point is less
+    /// likely to be tried first.
+    pub fn sample_path(&self, origin: &T, destination: &T, seed: u64) -> Result<Path<T>, NetErrors> {
+        self.sample_path_with_max_fanout(origin, destination, seed, usize::MAX)
+    }
+
+    /// Same as `sample_path`, but only tries a random subset of up to `max_fanout` connections
+    /// of each point, so that a point with a huge number of connections costs no more than
+    /// `max_fanout` draws. This approximates the distribution of `sample_path` further, and the
+    /// walk may miss every path to the destination when the subsets leave it out, in which case
+    /// it fails with `NoPathFound` rather than `DestinationUnreachable`.
+    pub fn sample_path_with_max_fanout(&self, origin: &T, destination: &T, seed: u64, max_fanout: usize) -> Result<Path<T>, NetErrors> {
+        self.sample_route(origin, destination, seed, max_fanout)
+            .map(|(path, _)| path)
+    }
+
+    /// Returns the sampled path along with the number of connections drawn to find it.
+    fn sample_route(&self, origin: &T, destination: &T, seed: u64, max_fanout: usize) -> Result<(Path<T>, usize), NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        if origin_position == destination_position {
+            return Ok((self.path_through_positions(&[origin_position])?, 0));
+        }
+
+        let mut random = SeededRandom::new(seed);
+        let draw = |random: &mut SeededRandom, position: usize| random.distinct_below(self.nodes[position].degree(), max_fanout);
+        let mut visited = vec![false; self.nodes.len()];
+        visited[origin_position] = true;
+        let mut route = vec![origin_position];
+        let mut pending = vec![draw(&mut random, origin_position)];
+        let mut drawn = pending[0].len();
+
+        while let Some(candidates) = pending.last_mut() {
+            let position = route[route.len() - 1];
+            match candidates.pop() {
+                Some(index) => {
+                    let next_position = self.position_or_throws(self.nodes[position].connected_point_at(index))?;
+                    if next_position == destination_position {
+                        route.push(next_position);
+                        return Ok((self.path_through_positions(&route)?, drawn));
+                    }
+                    if !visited[next_position] {
+                        visited[next_position] = true;
+                        route.push(next_position);
+                        let next_candidates = draw(&mut random, next_position);
+                        drawn += next_candidates.len();
+                        pending.push(next_candidates);
+                    }
+                },
+                None => {
+                    route.pop();
+                    pending.pop();
+                }
+            }
+        }
+
+        Err(self.missed_path_error(origin, destination))
+    }
+
+    /// Finds the path with the lowest total weight running Dijkstra's algorithm from both ends at
+    /// once and stopping when the two searches meet, which on large nets expands fewer points
+    /// than `shortest_path`. The path has the same cost as the one `shortest_path` finds, though
+    /// ties may be broken differently. Weights are expected to be non-negative.
+    pub fn shortest_path_bidirectional(&self, origin: &T, destination: &T) -> Result<Path<T>, NetErrors> {
+        self.bidirectional_search(origin, destination).map(|(path, _)| path)
+    }
+
+    /// Number of points `shortest_path_bidirectional` expands, counting both searches, before
+    /// they meet.
+    pub fn shortest_path_bidirectional_expansions(&self, origin: &T, destination: &T) -> Result<usize, NetErrors> {
+        self.bidirectional_search(origin, destination).map(|(_, expansions)| expansions)
+    }
+
+    /// Returns the path along with the number of points expanded by both searches to find it.
+    fn bidirectional_search(&self, origin: &T, destination: &T) -> Result<(Path<T>, usize), NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let (connections, reversed_connections) = self.weighted_connections()?;
+
+        let mut forward = DijkstraFrontier::new(self.nodes.len(), origin_position);
+        let mut backward = DijkstraFrontier::new(self.nodes.len(), destination_position);
+        let mut meeting: Option<(f64, usize)> = if origin_position == destination_position {
+            Some((0.0, origin_position))
+        } else {
+            None
+        };
+        let mut expansions = 0;
+
+        loop {
+            let best_cost = meeting.map_or(f64::INFINITY, |(cost, _)| cost);
+            let forward_cost = forward.next_cost();
+            let backward_cost = backward.next_cost();
+            if forward_cost + backward_cost >= best_cost || forward_cost.is_infinite() || backward_cost.is_infinite() {
+                break;
+            }
+
+            let (frontier, other_frontier, frontier_connections) = if forward_cost <= backward_cost {
+                (&mut forward, &backward, &connections)
+            } else {
+                (&mut backward, &forward, &reversed_connections)
+            };
+
+            expansions += 1;
+            for reached_position in frontier.expand(frontier_connections) {
+                if let (Some(cost), Some(other_cost)) = (frontier.costs[reached_position], other_frontier.costs[reached_position]) {
+                    if meeting.is_none_or(|(best_cost, _)| cost + other_cost < best_cost) {
+                        meeting = Some((cost + other_cost, reached_position));
+                    }
+                }
             }
         }
+
+        let meeting_position = match meeting {
+            Some((_, position)) => position,
+            None => return Err(unreachable_error(origin, destination))
+        };
+        let mut route = positions_from_previous(&forward.previous, meeting_position);
+        let mut position = meeting_position;
+        while let Some(next_position) = backward.previous[position] {
+            route.push(next_position);
+            position = next_position;
+        }
+
+        Ok((self.path_through_positions(&route)?, expansions))
+    }
+
+    /// Finds the path with the lowest total weight using Bellman-Ford, which unlike
+    /// `shortest_path` supports negative weights. Ties are broken by number of hops. Fails with
+    /// `NegativeCycle` if a cycle of negative total weight can be reached from `origin`, which
+    /// includes any undirected connection with a negative weight.
+    pub fn shortest_path_bellman_ford(&self, origin: &T, destination: &T) -> Result<Path<T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let connections = self.nodes.iter()
+            .map(|node| node.weighted_connected_points().into_iter()
+                .map(|(connected_point, weight)| Ok((self.position_or_throws(connected_point)?, weight)))
+                .collect::<Result<Vec<(usize, f64)>, NetErrors>>())
+            .collect::<Result<Vec<Vec<(usize, f64)>>, NetErrors>>()?;
+
+        let mut best: Vec<Option<(f64, usize)>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        best[origin_position] = Some((0.0, 0));
+
+        for _ in 1..self.nodes.len() {
+            let mut relaxed = false;
+            for (position, next_connections) in connections.iter().enumerate() {
+                let (cost, hops) = match best[position] {
+                    Some(best_so_far) => best_so_far,
+                    None => continue
+                };
+                for &(next_position, weight) in next_connections {
+                    let candidate = Candidate { priority: cost + weight, cost: cost + weight, hops: hops + 1, position: next_position };
+                    if best[next_position].is_none_or(|current| candidate.is_cheaper_than(current)) {
+                        best[next_position] = Some((candidate.cost, candidate.hops));
+                        previous[next_position] = Some(position);
+                        relaxed = true;
+                    }
+                }
+            }
+
+            if !relaxed {
+                break;
+            }
+        }
+
+        for (position, next_connections) in connections.iter().enumerate() {
+            if let Some((cost, _)) = best[position] {
+                let still_improves = next_connections.iter()
+                    .any(|&(next_position, weight)| best[next_position].is_some_and(|(next_cost, _)| cost + weight < next_cost));
+                if still_improves {
+                    return Err(NetErrors::NegativeCycle);
+                }
+            }
+        }
+
+        if best[destination_position].is_none() {
+            return Err(unreachable_error(origin, destination));
+        }
+
+        self.path_from_previous_positions(&previous, destination_position)
+    }
+
+    /// Finds up to `k` loopless paths sorted by total weight using Yen's algorithm. Paths with
+    /// the same cost are sorted by number of hops and then by the position of their points in
+    /// `nodes`, so the result is deterministic. Weights are expected to be non-negative.
+    pub fn k_shortest_paths(&self, origin: &T, destination: &T, k: usize) -> Result<Vec<Path<T>>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let no_blocked_points = vec![false; self.nodes.len()];
+        let first_route = self.cheapest_route_avoiding(origin_position, destination_position, &no_blocked_points, &[])?
+            .ok_or_else(|| unreachable_error(origin, destination))?;
+
+        let mut routes = vec![(self.path_through_positions(&first_route)?, first_route)];
+        let mut candidates: Vec<(Path<T>, Vec<usize>)> = Vec::new();
+
+        while routes.len() < k {
+            let last_route = routes[routes.len() - 1].1.clone();
+
+            for spur_index in 0..last_route.len() - 1 {
+                let root = &last_route[..=spur_index];
+                let blocked_edges: Vec<(usize, usize)> = routes.iter()
+                    .map(|(_, route)| route)
+                    .filter(|route| route.len() > spur_index + 1 && route[..=spur_index] == *root)
+                    .map(|route| (route[spur_index], route[spur_index + 1]))
+                    .collect();
+                let mut blocked_points = no_blocked_points.clone();
+                for &position in &root[..spur_index] {
+                    blocked_points[position] = true;
+                }
+
+                if let Some(spur_route) = self.cheapest_route_avoiding(root[spur_index], destination_position, &blocked_points, &blocked_edges)? {
+                    let route: Vec<usize> = root.iter().chain(&spur_route[1..]).cloned().collect();
+                    let is_known = routes.iter().chain(candidates.iter()).any(|(_, known_route)| *known_route == route);
+                    if !is_known {
+                        candidates.push((self.path_through_positions(&route)?, route));
+                    }
+                }
+            }
+
+            let cheapest_candidate = candidates.iter()
+                .enumerate()
+                .min_by(|(_, (path, route)), (_, (other_path, other_route))| path.cost().partial_cmp(&other_path.cost())
+                    .unwrap_or(Ordering::Equal)
+                    .then(path.hops().cmp(&other_path.hops()))
+                    .then(route.cmp(other_route)))
+                .map(|(index, _)| index);
+
+            match cheapest_candidate {
+                Some(index) => routes.push(candidates.swap_remove(index)),
+                None => break
+            }
+        }
+
+        Ok(routes.into_iter().map(|(path, _)| path).collect())
+    }
+
+    /// Ids of the points, in the order of `nodes`, along with the matrix where `[i][j]` is the
+    /// weight of the connection from the `i`th point to the `j`th one, or 0 if there is none.
+    /// Connections leading outside the net are ignored.
+    pub fn adjacency_matrix(&self) -> (Vec<T::Identifier>, Vec<Vec<f64>>) {
+        let ids = self.nodes.iter()
+            .map(|node| node.point().id().clone())
+            .collect();
+        let matrix = self.nodes.iter()
+            .map(|node| {
+                let mut row = vec![0.0; self.nodes.len()];
+                for (connected_point, weight) in node.weighted_connected_points() {
+                    if let Ok(position) = self.position_or_throws(connected_point) {
+                        row[position] = weight;
+                    }
+                }
+                row
+            })
+            .collect();
+
+        (ids, matrix)
+    }
+
+    /// Lowest total weight between every pair of points, computed with Floyd-Warshall so that
+    /// negative weights are supported as long as they do not form a negative cycle. Each point
+    /// is at distance zero from itself and unreachable pairs are left out. Connections leading
+    /// outside the net are ignored.
+    pub fn distance_matrix(&self) -> HashMap<(T::Identifier, T::Identifier), f64> {
+        let mut matrix = HashMap::new();
+        for (from, row) in self.floyd_warshall_distances().into_iter().enumerate() {
+            for (to, distance) in row.into_iter().enumerate() {
+                if let Some(distance) = distance {
+                    matrix.insert((self.nodes[from].point().id().clone(), self.nodes[to].point().id().clone()), distance);
+                }
+            }
+        }
+
+        matrix
+    }
+
+    /// Distances of `distance_matrix` by positions of the points, `None` for unreachable pairs.
+    fn floyd_warshall_distances(&self) -> Vec<Vec<Option<f64>>> {
+        let size = self.nodes.len();
+        let mut distances: Vec<Vec<Option<f64>>> = vec![vec![None; size]; size];
+
+        for (position, node) in self.nodes.iter().enumerate() {
+            distances[position][position] = Some(0.0);
+            for (connected_point, weight) in node.weighted_connected_points() {
+                if let Ok(next_position) = self.position_or_throws(connected_point) {
+                    distances[position][next_position] = Some(weight);
+                }
+            }
+        }
+
+        for middle in 0..size {
+            let middle_row = distances[middle].clone();
+            for row in &mut distances {
+                let from_middle = match row[middle] {
+                    Some(distance) => distance,
+                    None => continue
+                };
+                for (distance, middle_to) in row.iter_mut().zip(&middle_row) {
+                    if let Some(middle_to) = middle_to {
+                        let through_middle = from_middle + middle_to;
+                        if distance.is_none_or(|current| through_middle < current) {
+                            *distance = Some(through_middle);
+                        }
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Greatest of the lowest total weights between two points, taken from `distance_matrix`.
+    /// `None` for an empty net, or when some point cannot reach another one.
+    pub fn diameter(&self) -> Option<f64> {
+        let distances = self.distance_matrix();
+        if self.nodes.is_empty() || distances.len() < self.nodes.len() * self.nodes.len() {
+            return None;
+        }
+
+        distances.values().cloned().reduce(f64::max)
+    }
+
+    /// Greatest of the lowest total weights from `point` to every other point, which is
+    /// infinite when some point cannot be reached from it. The weights come from the same
+    /// Floyd-Warshall run as `distance_matrix`, so negative weights are supported as they are
+    /// by `diameter`.
+    pub fn eccentricity(&self, point: &T) -> Result<f64, NetErrors> {
+        let position = self.position_or_throws(point)?;
+        let distances = self.floyd_warshall_distances().swap_remove(position);
+
+        Ok(distances.into_iter().fold(0.0, |eccentricity, distance| eccentricity.max(distance.unwrap_or(f64::INFINITY))))
+    }
+
+    /// Copy of the net where every connection weighs `max` minus its weight, so that the
+    /// cheapest paths of the copy are the heaviest ones of this net. `max` must be at least the
+    /// weight of every connection, otherwise the copy has negative weights that Dijkstra's
+    /// algorithm does not handle.
+    pub fn with_inverted_weights(&self, max: f64) -> Net<T> {
+        Net::new(self.nodes.iter().map(|node| node.with_weights(|weight| max - weight)).collect())
+    }
+
+    /// Cheapest set of connections keeping every point linked, computed with Kruskal's algorithm
+    /// and returned as a new net with undirected connections. One-way connections are treated
+    /// as undirected. If the net is not connected the result is a spanning forest, with one
+    /// tree per connected part of the net.
+    pub fn minimum_spanning_tree(&self) -> Result<Net<T>, NetErrors> {
+        let mut edges = Vec::new();
+        for (position, node) in self.nodes.iter().enumerate() {
+            for (connected_point, weight) in node.weighted_connected_points() {
+                edges.push((position, self.position_or_throws(connected_point)?, weight));
+            }
+        }
+        edges.sort_by(|&(_, _, weight), &(_, _, other_weight)| weight.partial_cmp(&other_weight).unwrap_or(Ordering::Equal));
+
+        let mut parents: Vec<usize> = (0..self.nodes.len()).collect();
+        let mut builders: Vec<NodeBuilder<T>> = self.nodes.iter()
+            .map(|node| {
+                let mut builder = NodeBuilder::new();
+                builder.point(node.point());
+                builder
+            })
+            .collect();
+
+        for (from, to, weight) in edges {
+            let from_root = root_of(&mut parents, from);
+            let to_root = root_of(&mut parents, to);
+            if from_root != to_root {
+                parents[from_root] = to_root;
+                builders[from].connected_point_with_weight(self.nodes[to].point(), weight);
+                builders[to].connected_point_with_weight(self.nodes[from].point(), weight);
+            }
+        }
+
+        let nodes = builders.iter()
+            .map(|builder| builder.build().map_err(NetErrors::NodeCannotBeBuilt))
+            .collect::<Result<Vec<Node<T>>, NetErrors>>()?;
+
+        Ok(Net::new(nodes))
+    }
+
+    /// Smaller net for shortest path queries, where every maximal chain of points connected
+    /// only to their two neighbours is collapsed into a single connection weighing as much as
+    /// the whole chain. When several chains join the same points, the cheapest one is kept.
+    /// Paths found in it can be turned back into paths of this net with `expand_path`.
+    pub fn contract_chains(&self) -> Result<Net<T>, NetErrors> {
+        let chain_points = self.chain_points()?;
+        let mut nodes = Vec::new();
+
+        for (position, node) in self.nodes.iter().enumerate() {
+            if chain_points[position] {
+                continue;
+            }
+
+            let mut builder = NodeBuilder::new();
+            builder.point(node.point());
+            for route in self.cheapest_routes_from(position, &chain_points)? {
+                let end_point = self.nodes[route.end()].point();
+                if route.directed {
+                    builder.directed_connected_point_with_weight(end_point, route.cost);
+                } else {
+                    builder.connected_point_with_weight(end_point, route.cost);
+                }
+            }
+            nodes.push(builder.build().map_err(NetErrors::NodeCannotBeBuilt)?);
+        }
+
+        Ok(Net::new(nodes))
+    }
+
+    /// Path of this net going through every point of the chains collapsed by `contract_chains`
+    /// along `contracted_path`, a path found in the contracted net.
+    pub fn expand_path(&self, contracted_path: &Path<T>) -> Result<Path<T>, NetErrors> {
+        let chain_points = self.chain_points()?;
+        let origin = contracted_path.points().first()
+            .ok_or_else(|| NetErrors::PathCannotBeBuilt(String::from("Path should have at least one point")))?;
+        let mut positions = vec![self.position_or_throws(origin)?];
+
+        for pair in contracted_path.points().windows(2) {
+            let from = self.position_or_throws(&pair[0])?;
+            let to = self.position_or_throws(&pair[1])?;
+            let route = self.cheapest_routes_from(from, &chain_points)?.into_iter()
+                .find(|route| route.end() == to)
+                .ok_or_else(|| NetErrors::PathCannotBeBuilt(format!(r#""{}" is not joined to "{}""#, pair[0].id().to_string(), pair[1].id().to_string())))?;
+            positions.extend_from_slice(&route.positions[1..]);
+        }
+
+        self.path_through_positions(&positions)
+    }
+
+    /// Same paths as `find_paths`, grouped by the id of the point each of them goes through
+    /// right before reaching the destination. The path made of the destination alone, when it
+    /// is also the origin, is grouped under its own id.
+    pub fn find_paths_by_last_edge(&self, origin: &T, destination: &T) -> Result<HashMap<T::Identifier, Vec<Path<T>>>, NetErrors> {
+        let mut paths_by_last_edge: HashMap<T::Identifier, Vec<Path<T>>> = HashMap::new();
+
+        for path in self.find_paths(origin, destination)? {
+            let last_edge_origin = path.point_before_last().unwrap_or(destination).id().clone();
+
+            paths_by_last_edge.entry(last_edge_origin)
+                .or_default()
+                .push(path);
+        }
+
+        Ok(paths_by_last_edge)
+    }
+
+    /// Two points are 2-edge-connected when they stay connected after removing any single edge,
+    /// that is, when the minimum edge cut between them is at least two.
+    pub fn is_two_edge_connected(&self, a: &T, b: &T) -> Result<bool, NetErrors> {
+        if !self.can_reach_without_edge(a, b, None)? {
+            return Ok(false);
+        }
+
+        for node in &self.nodes {
+            for connected_point in node.connected_points() {
+                if !self.can_reach_without_edge(a, b, Some((node.point(), connected_point)))? {
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Whether any path leads from `origin` to `destination`, without building the paths: the
+    /// traversal stops as soon as it reaches the destination.
+    pub fn is_reachable(&self, origin: &T, destination: &T) -> Result<bool, NetErrors> {
+        self.can_reach_without_edge(origin, destination, None)
+    }
+
+    /// Every point reachable from `origin`, starting with the origin itself, in breadth-first
+    /// order. Neighbours are visited in the order their connections were declared.
+    pub fn reachable_from(&self, origin: &T) -> Result<Vec<&T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let mut visited = vec![false; self.nodes.len()];
+        visited[origin_position] = true;
+        let mut reached = vec![origin_position];
+        let mut next = 0;
+
+        while let Some(&position) = reached.get(next) {
+            next += 1;
+            for connected_point in self.nodes[position].connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                if !visited[next_position] {
+                    visited[next_position] = true;
+                    reached.push(next_position);
+                }
+            }
+        }
+
+        Ok(reached.into_iter()
+            .map(|position| self.nodes[position].point())
+            .collect())
+    }
+
+    /// Every connection as a pair of points, following the order of `nodes`. An undirected edge
+    /// is declared on both of its points but is yielded only once, from the point found first:
+    /// `(A, B)` is never followed by `(B, A)`. One-way connections are yielded in their own
+    /// direction.
+    pub fn edges(&self) -> impl Iterator<Item = (&T, &T)> + '_ {
+        self.nodes.iter()
+            .enumerate()
+            .flat_map(move |(position, node)| node.connected_points().into_iter()
+                .filter(move |connected_point| node.is_directed_to(connected_point)
+                    || !self.is_declared_back_before(position, node.point(), connected_point))
+                .map(move |connected_point| (node.point(), connected_point)))
+    }
+
+    /// Every connection as an owned pair of ids, as yielded by `edges`.
+    pub fn to_edge_list(&self) -> Vec<(T::Identifier, T::Identifier)> {
+        self.edges()
+            .map(|(from, to)| (from.id().clone(), to.id().clone()))
+            .collect()
+    }
+
+    /// A net is directed when any of its connections is one-way.
+    pub fn is_directed(&self) -> bool {
+        self.nodes.iter().any(|node| node.has_directed_connections())
+    }
+
+    /// Whether any two points are joined by more than one connection, making the net a
+    /// multigraph rather than a simple net.
+    pub fn has_parallel_edges(&self) -> bool {
+        !self.parallel_edge_pairs().is_empty()
+    }
+
+    /// Turns a multigraph into a simple net by replacing the connections joining the same points
+    /// with a single one, weighing what folding their weights with `combine` gives, such as
+    /// `f64::min` to keep the cheapest. The merged connection takes the place and the direction
+    /// of the first one. Nodes keep their positions, so only the version of the net changes.
+    pub fn merge_parallel_edges(&mut self, combine: impl Fn(f64, f64) -> f64) {
+        for node in &mut self.nodes {
+            node.merge_parallel_connections(&combine);
+        }
+        self.touch();
+    }
+
+    /// Ids of the points joined by more than one connection, following the order of `nodes`. As
+    /// in `edges`, undirected pairs are reported once, from the point found first.
+    pub fn parallel_edge_pairs(&self) -> Vec<(T::Identifier, T::Identifier)> {
+        let mut pairs = Vec::new();
+        for node in &self.nodes {
+            let mut counts: HashMap<&T::Identifier, usize> = HashMap::new();
+            for connected_point in node.connected_points() {
+                let count = counts.entry(connected_point.id()).or_insert(0);
+                *count += 1;
+                let reported_back = pairs.contains(&(connected_point.id().clone(), node.point().id().clone()));
+                if *count == 2 && (node.is_directed_to(connected_point) || !reported_back) {
+                    pairs.push((node.point().id().clone(), connected_point.id().clone()));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Finds the path whose busiest intermediate point (the one with the most connections) is as
+    /// quiet as possible. The degrees of the origin and the destination do not count, and ties
+    /// are broken by the number of hops.
+    pub fn find_path_min_max_degree(&self, origin: &T, destination: &T) -> Result<Path<T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+
+        let mut best: Vec<Option<(usize, usize)>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut frontier = BinaryHeap::new();
+        best[origin_position] = Some((0, 0));
+        frontier.push(Reverse((0, 0, origin_position)));
+
+        while let Some(Reverse((max_degree, hops, position))) = frontier.pop() {
+            if best[position] != Some((max_degree, hops)) {
+                continue;
+            }
+
+            if position == destination_position {
+                return self.path_from_previous_positions(&previous, destination_position);
+            }
+
+            let node = &self.nodes[position];
+            let max_degree_after_leaving = if position == origin_position {
+                max_degree
+            } else {
+                max_degree.max(node.degree())
+            };
+
+            for connected_point in node.connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                let candidate = (max_degree_after_leaving, hops + 1);
+                let improves = match best[next_position] {
+                    Some(current) => candidate < current,
+                    None => true
+                };
+
+                if improves {
+                    best[next_position] = Some(candidate);
+                    previous[next_position] = Some(position);
+                    frontier.push(Reverse((candidate.0, candidate.1, next_position)));
+                }
+            }
+        }
+
+        Err(unreachable_error(origin, destination))
+    }
+
+    /// Fraction of pairs of neighbours of `point` that are connected to each other. Points with
+    /// fewer than two neighbours have a coefficient of zero.
+    pub fn clustering_coefficient(&self, point: &T) -> Result<f64, NetErrors> {
+        let node = self.find_node_or_throws(point)?;
+        let neighbours = node.connected_points();
+
+        if neighbours.len() < 2 {
+            return Ok(0.0);
+        }
+
+        let mut connected_pairs = 0;
+        for (index, neighbour) in neighbours.iter().enumerate() {
+            let neighbour_node = self.find_node_or_throws(neighbour)?;
+            connected_pairs += neighbours[index + 1..].iter()
+                .filter(|other_neighbour| neighbour_node.is_connected_to(other_neighbour))
+                .count();
+        }
+
+        let possible_pairs = neighbours.len() * (neighbours.len() - 1) / 2;
+        Ok(connected_pairs as f64 / possible_pairs as f64)
+    }
+
+    /// Mean of the clustering coefficients of every point, zero for an empty net. A point whose
+    /// coefficient cannot be computed, because one of its connections leads outside the net,
+    /// counts as zero; `clustering_coefficient` or `validate` report such connections.
+    pub fn average_clustering(&self) -> f64 {
+        if self.nodes.is_empty() {
+            return 0.0;
+        }
+
+        let total: f64 = self.nodes.iter()
+            .map(|node| self.clustering_coefficient(node.point()).unwrap_or(0.0))
+            .sum();
+
+        total / self.nodes.len() as f64
+    }
+
+    /// Mean number of connections per point, `2E / V` for an undirected net. An empty net has an
+    /// average degree of zero.
+    pub fn average_degree(&self) -> f64 {
+        if self.nodes.is_empty() {
+            return 0.0;
+        }
+
+        let connections: usize = self.nodes.iter()
+            .map(|node| node.degree())
+            .sum();
+
+        connections as f64 / self.nodes.len() as f64
+    }
+
+    /// Minimum number of connections that would have to be added to join every point into a
+    /// single connected net, that is, the number of connected components minus one.
+    pub fn edges_to_connect(&self) -> Result<usize, NetErrors> {
+        let components = self.undirected_components()?;
+        Ok(components.saturating_sub(1))
+    }
+
+    /// Whether every point can be reached from any other when connections are followed in both
+    /// directions. An empty net is connected, one with dangling connections is not.
+    pub fn is_connected(&self) -> bool {
+        match self.undirected_components() {
+            Ok(components) => components <= 1,
+            Err(_) => false
+        }
+    }
+
+    /// Whether every point can be reached from any other following one-way connections only in
+    /// their own direction. An empty net is strongly connected.
+    pub fn is_strongly_connected(&self) -> bool {
+        let first_point = match self.nodes.first() {
+            Some(node) => node.point(),
+            None => return true
+        };
+
+        let reaches_every_point = match self.reachable_from(first_point) {
+            Ok(reached) => reached.len() == self.nodes.len(),
+            Err(_) => false
+        };
+
+        reaches_every_point && self.nodes.iter()
+            .all(|node| self.is_reachable(node.point(), first_point).unwrap_or(false))
+    }
+
+    /// Groups of points that can all reach each other, found with Kosaraju's algorithm. Points
+    /// outside any cycle form their own group. Groups are listed in the order of their first
+    /// point in `nodes`, and points keep that order within each group. Connections leading
+    /// outside the net are ignored.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&T>> {
+        let connections: Vec<Vec<usize>> = self.nodes.iter()
+            .map(|node| node.connected_points().into_iter()
+                .filter_map(|connected_point| self.position_or_throws(connected_point).ok())
+                .collect())
+            .collect();
+        let mut reversed_connections: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (position, next_positions) in connections.iter().enumerate() {
+            for &next_position in next_positions {
+                reversed_connections[next_position].push(position);
+            }
+        }
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut finished = Vec::new();
+        for start in 0..self.nodes.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut pending = vec![(start, 0)];
+
+            while let Some((position, next_connection)) = pending.pop() {
+                match connections[position].get(next_connection) {
+                    Some(&next_position) => {
+                        pending.push((position, next_connection + 1));
+                        if !visited[next_position] {
+                            visited[next_position] = true;
+                            pending.push((next_position, 0));
+                        }
+                    },
+                    None => finished.push(position)
+                }
+            }
+        }
+
+        let mut component_of: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut components = 0;
+        for &start in finished.iter().rev() {
+            if component_of[start].is_some() {
+                continue;
+            }
+            component_of[start] = Some(components);
+            let mut pending = vec![start];
+
+            while let Some(position) = pending.pop() {
+                for &previous_position in &reversed_connections[position] {
+                    if component_of[previous_position].is_none() {
+                        component_of[previous_position] = Some(components);
+                        pending.push(previous_position);
+                    }
+                }
+            }
+            components += 1;
+        }
+
+        let mut groups: Vec<Vec<&T>> = Vec::new();
+        let mut group_of_component: Vec<Option<usize>> = vec![None; components];
+        for (node, component) in self.nodes.iter().zip(component_of) {
+            let component = component.expect("every point belongs to a component");
+            match group_of_component[component] {
+                Some(group) => groups[group].push(node.point()),
+                None => {
+                    group_of_component[component] = Some(groups.len());
+                    groups.push(vec![node.point()]);
+                }
+            }
+        }
+
+        groups
+    }
+
+    /// Orders the points so that every point comes before the points it connects to, using
+    /// Kahn's algorithm. When several points could come next, the one added to the net first
+    /// goes first. Two-way connections count as a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<&T>, NetErrors> {
+        let sorted = self.topological_positions()?;
+
+        Ok(sorted.into_iter().map(|position| self.nodes[position].point()).collect())
+    }
+
+    /// Heaviest path from `origin` to `destination`, along with its total weight, as the
+    /// critical path method computes it: longest distances are relaxed in topological order.
+    /// Only makes sense in a net without cycles and fails with `CycleDetected` otherwise.
+    pub fn critical_path(&self, origin: &T, destination: &T) -> Result<(Path<T>, f64), NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+        let sorted = self.topological_positions()?;
+
+        let mut longest: Vec<Option<f64>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        longest[origin_position] = Some(0.0);
+        for position in sorted {
+            let cost = match longest[position] {
+                Some(cost) => cost,
+                None => continue
+            };
+            for (connected_point, weight) in self.nodes[position].weighted_connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                if longest[next_position].is_none_or(|current| cost + weight > current) {
+                    longest[next_position] = Some(cost + weight);
+                    previous[next_position] = Some(position);
+                }
+            }
+        }
+
+        match longest[destination_position] {
+            Some(cost) => Ok((self.path_from_previous_positions(&previous, destination_position)?, cost)),
+            None => Err(unreachable_error(origin, destination))
+        }
+    }
+
+    /// Positions of the nodes in the order `topological_sort` returns their points.
+    fn topological_positions(&self) -> Result<Vec<usize>, NetErrors> {
+        let mut connections: Vec<Vec<usize>> = Vec::with_capacity(self.nodes.len());
+        let mut incoming = vec![0; self.nodes.len()];
+        for node in &self.nodes {
+            let mut next_positions = Vec::new();
+            for connected_point in node.connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                incoming[next_position] += 1;
+                next_positions.push(next_position);
+            }
+            connections.push(next_positions);
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = incoming.iter().enumerate()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(position, _)| Reverse(position))
+            .collect();
+        let mut sorted = Vec::with_capacity(self.nodes.len());
+        while let Some(Reverse(position)) = ready.pop() {
+            sorted.push(position);
+            for &next_position in &connections[position] {
+                incoming[next_position] -= 1;
+                if incoming[next_position] == 0 {
+                    ready.push(Reverse(next_position));
+                }
+            }
+        }
+
+        if sorted.len() < self.nodes.len() {
+            return Err(NetErrors::CycleDetected);
+        }
+
+        Ok(sorted)
+    }
+
+    /// Points `point` is directly connected to, in the order its connections were declared.
+    pub fn neighbors(&self, point: &T) -> Result<Vec<&T>, NetErrors> {
+        Ok(self.find_node_or_throws(point)?.connected_points())
+    }
+
+    /// Number of connections of `point`. For one-way connections this is the out-degree.
+    pub fn degree_of(&self, point: &T) -> Result<usize, NetErrors> {
+        Ok(self.find_node_or_throws(point)?.degree())
+    }
+
+    /// Number of connections leaving `point`.
+    pub fn out_degree_of(&self, point: &T) -> Result<usize, NetErrors> {
+        self.degree_of(point)
+    }
+
+    /// Number of connections from other points leading to `point`.
+    pub fn in_degree_of(&self, point: &T) -> Result<usize, NetErrors> {
+        self.find_node_or_throws(point)?;
+
+        Ok(self.nodes.iter()
+            .filter(|node| node.is_connected_to(point))
+            .count())
+    }
+
+    /// Points with their number of connections, most connected first. Points with the same
+    /// degree keep the order of `nodes`.
+    pub fn points_by_degree(&self) -> Vec<(&T, usize)> {
+        let mut degrees: Vec<(&T, usize)> = self.nodes.iter()
+            .map(|node| (node.point(), node.degree()))
+            .collect();
+
+        degrees.sort_by_key(|&(_, degree)| Reverse(degree));
+        degrees
+    }
+
+    /// Points ranked by their number of connections divided by the number of other points in the
+    /// net, most connected first. Points with the same centrality keep the order of `nodes`.
+    pub fn degree_centrality(&self) -> Vec<(T::Identifier, f64)> {
+        let other_points = self.nodes.len().saturating_sub(1);
+        let mut centralities: Vec<(T::Identifier, f64)> = self.nodes.iter()
+            .map(|node| {
+                let centrality = if other_points == 0 {
+                    0.0
+                } else {
+                    node.degree() as f64 / other_points as f64
+                };
+                (node.point().id().clone(), centrality)
+            })
+            .collect();
+
+        centralities.sort_by(|&(_, a), &(_, b)| b.partial_cmp(&a).unwrap_or(Ordering::Equal));
+        centralities
+    }
+
+    /// Ids of the points, in the order of `nodes`, from which `destination` cannot be reached.
+    pub fn cannot_reach(&self, destination: &T) -> Result<Vec<T::Identifier>, NetErrors> {
+        let destination_position = self.position_or_throws(destination)?;
+
+        let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (position, node) in self.nodes.iter().enumerate() {
+            for connected_point in node.connected_points() {
+                incoming[self.position_or_throws(connected_point)?].push(position);
+            }
+        }
+
+        let mut reaches_destination = vec![false; self.nodes.len()];
+        reaches_destination[destination_position] = true;
+        let mut pending = vec![destination_position];
+        while let Some(position) = pending.pop() {
+            for &previous_position in &incoming[position] {
+                if !reaches_destination[previous_position] {
+                    reaches_destination[previous_position] = true;
+                    pending.push(previous_position);
+                }
+            }
+        }
+
+        Ok(self.nodes.iter()
+            .zip(reaches_destination)
+            .filter(|&(_, reaches)| !reaches)
+            .map(|(node, _)| node.point().id().clone())
+            .collect())
+    }
+
+    /// Every simple cycle of the net as a closed path that starts and ends at the same point,
+    /// e.g. `A-B-C-D-A`, starting at its earliest point in `nodes`. A cycle made only of
+    /// undirected connections is reported once regardless of its orientation and needs at least
+    /// three points; one following any one-way connection is reported in its own direction and
+    /// may have two points. The number of cycles, and so the running time, can grow
+    /// exponentially with the size of the net.
+    pub fn simple_cycles(&self) -> Result<Vec<Path<T>>, NetErrors> {
+        let mut cycles = Vec::new();
+
+        for start in 0..self.nodes.len() {
+            let mut in_path = vec![false; self.nodes.len()];
+            in_path[start] = true;
+            self.collect_cycles_closing_at(start, &mut vec![start], &mut in_path, &mut cycles)?;
+        }
+
+        Ok(cycles)
+    }
+
+    fn collect_cycles_closing_at(&self, start: usize, current_path: &mut Vec<usize>, in_path: &mut [bool], cycles: &mut Vec<Path<T>>) -> Result<(), NetErrors> {
+        let last_position = *current_path.last().unwrap();
+
+        for connected_point in self.nodes[last_position].connected_points() {
+            let next_position = self.position_or_throws(connected_point)?;
+
+            if next_position == start {
+                current_path.push(start);
+                let undirected = current_path.windows(2)
+                    .all(|hop| !self.nodes[hop[0]].is_directed_to(self.nodes[hop[1]].point()));
+                let reported_once = !undirected || (current_path.len() >= 4 && current_path[1] < last_position);
+                if current_path.len() >= 3 && reported_once {
+                    cycles.push(self.path_through_positions(current_path)?);
+                }
+                current_path.pop();
+            } else if next_position > start && !in_path[next_position] {
+                in_path[next_position] = true;
+                current_path.push(next_position);
+                self.collect_cycles_closing_at(start, current_path, in_path, cycles)?;
+                current_path.pop();
+                in_path[next_position] = false;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the path crossing the fewest connections for which `is_expensive` holds, breaking
+    /// ties by number of hops. Runs a 0/1 breadth-first search where expensive connections
+    /// cost one and the rest cost nothing.
+    pub fn find_path_min_expensive<F: Fn(&T, &T) -> bool>(&self, origin: &T, destination: &T, is_expensive: F) -> Result<Path<T>, NetErrors> {
+        let origin_position = self.position_or_throws(origin)?;
+        let destination_position = self.position_or_throws(destination)?;
+
+        let mut best: Vec<Option<(usize, usize)>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut pending = VecDeque::new();
+        best[origin_position] = Some((0, 0));
+        pending.push_back((origin_position, (0, 0)));
+
+        while let Some((position, label)) = pending.pop_front() {
+            if best[position] != Some(label) {
+                continue;
+            }
+
+            let (expensive_edges, hops) = label;
+            let node = &self.nodes[position];
+            for connected_point in node.connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                let edge_is_expensive = is_expensive(node.point(), connected_point);
+                let candidate = (expensive_edges + edge_is_expensive as usize, hops + 1);
+                let improves = match best[next_position] {
+                    Some(current) => candidate < current,
+                    None => true
+                };
+
+                if improves {
+                    best[next_position] = Some(candidate);
+                    previous[next_position] = Some(position);
+                    if edge_is_expensive {
+                        pending.push_back((next_position, candidate));
+                    } else {
+                        pending.push_front((next_position, candidate));
+                    }
+                }
+            }
+        }
+
+        match best[destination_position] {
+            Some(_) => self.path_from_previous_positions(&previous, destination_position),
+            None => Err(unreachable_error(origin, destination))
+        }
+    }
+
+    /// Finds the cheapest route from `origin` to `destination` that visits every waypoint,
+    /// choosing the best visiting order with Held-Karp over the lowest total weights between
+    /// stops. The route is made of cheapest segments between consecutive stops, so it may go
+    /// through a point more than once, which is why it is a `Walk` rather than a `Path`. It takes
+    /// O(2^k·k²) steps for k waypoints, which keeps it practical up to a dozen or so waypoints.
+    /// Fails with `TooManyWaypoints` above `MAX_TOUR_WAYPOINTS`. Weights are expected to be
+    /// non-negative.
+    pub fn shortest_tour(&self, origin: &T, waypoints: &[&T], destination: &T) -> Result<Walk<T>, NetErrors> {
+        if waypoints.len() > MAX_TOUR_WAYPOINTS {
+            return Err(NetErrors::TooManyWaypoints(waypoints.len(), MAX_TOUR_WAYPOINTS));
+        }
+
+        let mut stops = vec![self.position_or_throws(origin)?];
+        for waypoint in waypoints {
+            stops.push(self.position_or_throws(waypoint)?);
+        }
+        stops.push(self.position_or_throws(destination)?);
+
+        let searches = stops.iter()
+            .map(|&stop| self.cheapest_tree_from(stop, false))
+            .collect::<Result<Vec<_>, NetErrors>>()?;
+        let cost_between = |from: usize, to: usize| searches[from].costs[stops[to]];
+
+        let waypoint_count = waypoints.len();
+        let destination_stop = waypoint_count + 1;
+        let visiting_order = if waypoint_count == 0 {
+            Vec::new()
+        } else {
+            let all_visited = (1 << waypoint_count) - 1;
+            let mut best: Vec<Vec<Option<(f64, usize)>>> = vec![vec![None; waypoint_count]; 1 << waypoint_count];
+
+            for last in 0..waypoint_count {
+                if let Some(cost) = cost_between(0, last + 1) {
+                    best[1 << last][last] = Some((cost, last));
+                }
+            }
+
+            for visited in 1..=all_visited {
+                for last in 0..waypoint_count {
+                    let cost = match best[visited][last] {
+                        Some((cost, _)) => cost,
+                        None => continue
+                    };
+
+                    for next in (0..waypoint_count).filter(|next| visited & (1 << next) == 0) {
+                        if let Some(segment) = cost_between(last + 1, next + 1) {
+                            let next_visited = visited | (1 << next);
+                            let candidate = cost + segment;
+                            if best[next_visited][next].is_none_or(|(current, _)| candidate < current) {
+                                best[next_visited][next] = Some((candidate, last));
+                            }
+                        }
+                    }
+                }
+            }
+
+            let closing_leg = (0..waypoint_count)
+                .filter_map(|last| match (best[all_visited][last], cost_between(last + 1, destination_stop)) {
+                    (Some((cost, _)), Some(segment)) => Some((cost + segment, last)),
+                    _ => None
+                })
+                .min_by(|(cost, _), (other_cost, _)| cost.partial_cmp(other_cost).unwrap_or(Ordering::Equal));
+
+            let mut last = match closing_leg {
+                Some((_, last)) => last,
+                None => return Err(self.unreachable_stops_error(&stops, &searches))
+            };
+
+            let mut order = Vec::new();
+            let mut visited = all_visited;
+            while visited != 0 {
+                order.push(last + 1);
+                let (_, previous_last) = best[visited][last].unwrap();
+                visited &= !(1 << last);
+                last = previous_last;
+            }
+            order.reverse();
+            order
+        };
+
+        let mut route = vec![stops[0]];
+        let mut from = 0;
+        for to in visiting_order.into_iter().chain(Some(destination_stop)) {
+            if cost_between(from, to).is_none() {
+                return Err(unreachable_error(self.nodes[stops[from]].point(), self.nodes[stops[to]].point()));
+            }
+            route.extend(positions_from_previous(&searches[from].previous, stops[to]).into_iter().skip(1));
+            from = to;
+        }
+
+        let mut cost = 0.0;
+        for hop in route.windows(2) {
+            let (point, next_point) = (self.nodes[hop[0]].point(), self.nodes[hop[1]].point());
+            cost += self.nodes[hop[0]].weight_to(next_point)
+                .ok_or_else(|| NetErrors::NotAnEdge(point.id().to_string(), next_point.id().to_string()))?;
+        }
+        let points = route.iter().map(|&position| self.nodes[position].point().clone()).collect();
+
+        Ok(Walk::new(points, cost))
+    }
+
+    /// `DestinationUnreachable` for the first pair of stops of a tour, in order, where no route
+    /// leads from one stop to the other, given the cheapest routes from each stop. Routes back
+    /// to the origin or leaving the destination are not needed by the tour and are skipped.
+    fn unreachable_stops_error(&self, stops: &[usize], searches: &[DijkstraFrontier]) -> NetErrors {
+        for (from, search) in searches.iter().enumerate().take(stops.len() - 1) {
+            if let Some(&stop) = stops[1..].iter().find(|&&stop| search.costs[stop].is_none()) {
+                return unreachable_error(self.nodes[stops[from]].point(), self.nodes[stop].point());
+            }
+        }
+
+        NetErrors::NoPathFound
+    }
+
+    /// `DestinationUnreachable` when no route leads from `origin` to `destination`, otherwise
+    /// `NoPathFound`, for searches that may leave out routes that do exist.
+    fn missed_path_error(&self, origin: &T, destination: &T) -> NetErrors {
+        match self.is_reachable(origin, destination) {
+            Ok(true) => NetErrors::NoPathFound,
+            Ok(false) => unreachable_error(origin, destination),
+            Err(error) => error
+        }
+    }
+
+    fn find_paths_not_crossing_previous_path(&self, origin: &Node<T>, destination: &T, previous_path: &Path<T>, max_hops: usize) -> Result<Option<Vec<Path<T>>>, NetErrors> {
+        match origin.weighted_points_not_in_path(previous_path) {
+            None => Ok(None),
+            Some(followable_points) => {
+                let paths = self.all_paths_to_destination_following_path_and_continuing_with_points(destination, previous_path, followable_points, max_hops)?;
+
+                if paths.is_empty() {
+                    Ok(None)
+                } else {
+                    Ok(Some(paths))
+                }
+            }
+        }
+    }
+
+    fn all_paths_to_destination_following_path_and_continuing_with_points(&self, destination: &T, previous_path: &Path<T>, followable_points: Vec<(&T, f64)>, max_hops: usize) -> Result<Vec<Path<T>>, NetErrors> {
+        followable_points
+            .into_iter()
+            .map(|(point, weight)| self.all_paths_to_destination_following_path_and_continuing_with_point(destination, previous_path, point, weight, max_hops))
+            .try_fold(Vec::new(), |paths: Vec<Path<T>>, path_search: Result<Option<Vec<Path<T>>>, NetErrors>|
+                match path_search? {
+                    Some(paths_found) => Ok(paths.into_iter().chain(paths_found).collect()),
+                    None => Ok(paths)
+                },
+            )
+    }
+
+    fn all_paths_to_destination_following_path_and_continuing_with_point(&self, destination: &T, following_path: &Path<T>, next_point: &T, weight: f64, max_hops: usize) -> Result<Option<Vec<Path<T>>>, NetErrors> {
+        if following_path.hops() + 2 > max_hops {
+            return Ok(None);
+        }
+
+        let origin_node = self.find_node_or_throws(next_point)?;
+        let trying_path = following_path.with_weighted_point_at_the_end(next_point, weight);
+        if trying_path.ends_with(destination) {
+            Ok(Some(vec![trying_path]))
+        } else {
+            self.find_paths_not_crossing_previous_path(origin_node, destination, &trying_path, max_hops)
+        }
+    }
+
+    fn can_reach_without_edge(&self, origin: &T, destination: &T, removed_edge: Option<(&T, &T)>) -> Result<bool, NetErrors> {
+        let is_removed = |from: &T, to: &T| match removed_edge {
+            Some((a, b)) => (from.is(a) && to.is(b)) || (from.is(b) && to.is(a)),
+            None => false
+        };
+
+        self.find_node_or_throws(destination)?;
+        let origin_position = self.position_or_throws(origin)?;
+        let mut visited = vec![false; self.nodes.len()];
+        visited[origin_position] = true;
+        let mut pending = vec![origin_position];
+
+        while let Some(position) = pending.pop() {
+            let node = &self.nodes[position];
+            if node.point_is(destination) {
+                return Ok(true);
+            }
+
+            for connected_point in node.connected_points() {
+                if is_removed(node.point(), connected_point) {
+                    continue;
+                }
+
+                let next_position = self.position_or_throws(connected_point)?;
+                if !visited[next_position] {
+                    visited[next_position] = true;
+                    pending.push(next_position);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn path_from_previous_positions(&self, previous: &[Option<usize>], destination_position: usize) -> Result<Path<T>, NetErrors> {
+        self.path_through_positions(&positions_from_previous(previous, destination_position))
+    }
+
+    /// Dijkstra's algorithm over positions, ignoring the blocked points and connections.
+    fn cheapest_route_avoiding(&self, origin_position: usize, destination_position: usize, blocked_points: &[bool], blocked_edges: &[(usize, usize)]) -> Result<Option<Vec<usize>>, NetErrors> {
+        let mut best: Vec<Option<(f64, usize)>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut frontier = BinaryHeap::new();
+        best[origin_position] = Some((0.0, 0));
+        frontier.push(Candidate { priority: 0.0, cost: 0.0, hops: 0, position: origin_position });
+
+        while let Some(Candidate { cost, hops, position, .. }) = frontier.pop() {
+            if best[position] != Some((cost, hops)) {
+                continue;
+            }
+
+            if position == destination_position {
+                return Ok(Some(positions_from_previous(&previous, destination_position)));
+            }
+
+            for (connected_point, weight) in self.nodes[position].weighted_connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                if blocked_points[next_position] || blocked_edges.contains(&(position, next_position)) {
+                    continue;
+                }
+
+                let candidate = Candidate { priority: cost + weight, cost: cost + weight, hops: hops + 1, position: next_position };
+                if best[next_position].is_none_or(|current| candidate.is_cheaper_than(current)) {
+                    best[next_position] = Some((candidate.cost, candidate.hops));
+                    previous[next_position] = Some(position);
+                    frontier.push(candidate);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn undirected_components(&self) -> Result<usize, NetErrors> {
+        let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (position, node) in self.nodes.iter().enumerate() {
+            for connected_point in node.connected_points() {
+                let connected_position = self.position_or_throws(connected_point)?;
+                neighbours[position].push(connected_position);
+                neighbours[connected_position].push(position);
+            }
+        }
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut components = 0;
+        for start in 0..self.nodes.len() {
+            if visited[start] {
+                continue;
+            }
+
+            components += 1;
+            visited[start] = true;
+            let mut pending = vec![start];
+            while let Some(position) = pending.pop() {
+                for &next_position in &neighbours[position] {
+                    if !visited[next_position] {
+                        visited[next_position] = true;
+                        pending.push(next_position);
+                    }
+                }
+            }
+        }
+
+        Ok(components)
+    }
+
+    fn hop_distances_from(&self, origin_position: usize) -> Result<HopDistances, NetErrors> {
+        let mut distances = vec![None; self.nodes.len()];
+        let mut previous = vec![None; self.nodes.len()];
+        let mut pending = VecDeque::new();
+        distances[origin_position] = Some(0);
+        pending.push_back(origin_position);
+
+        while let Some(position) = pending.pop_front() {
+            let distance = distances[position].unwrap();
+            for connected_point in self.nodes[position].connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                if distances[next_position].is_none() {
+                    distances[next_position] = Some(distance + 1);
+                    previous[next_position] = Some(position);
+                    pending.push_back(next_position);
+                }
+            }
+        }
+
+        Ok(HopDistances { distances, previous })
+    }
+
+    /// Cost of the cheapest route from `start` to every point, or from every point to `start`
+    /// when `reversed`, computed with Dijkstra's algorithm.
+    pub(crate) fn cheapest_costs_from(&self, start: usize, reversed: bool) -> Result<Vec<Option<f64>>, NetErrors> {
+        self.cheapest_tree_from(start, reversed).map(|tree| tree.costs)
+    }
+
+    /// Same costs as `cheapest_costs_from`, along with the previous position on the cheapest
+    /// route to every point.
+    fn cheapest_tree_from(&self, start: usize, reversed: bool) -> Result<DijkstraFrontier, NetErrors> {
+        let (connections, reversed_connections) = self.weighted_connections()?;
+        let connections = if reversed { &reversed_connections } else { &connections };
+
+        let mut frontier = DijkstraFrontier::new(self.nodes.len(), start);
+        while frontier.next_cost().is_finite() {
+            frontier.expand(connections);
+        }
+
+        Ok(frontier)
+    }
+
+    /// Positions and weights of the connections leaving every point, and of those reaching it.
+    fn weighted_connections(&self) -> Result<(WeightedConnections, WeightedConnections), NetErrors> {
+        let mut connections: WeightedConnections = vec![Vec::new(); self.nodes.len()];
+        let mut reversed_connections: WeightedConnections = vec![Vec::new(); self.nodes.len()];
+        for (position, node) in self.nodes.iter().enumerate() {
+            for (connected_point, weight) in node.weighted_connected_points() {
+                let next_position = self.position_or_throws(connected_point)?;
+                connections[position].push((next_position, weight));
+                reversed_connections[next_position].push((position, weight));
+            }
+        }
+
+        Ok((connections, reversed_connections))
+    }
+
+    /// Whether each point lies inside a chain that `contract_chains` collapses: it has exactly
+    /// two connections and both neighbours connect back to it, all of them undirected. One point
+    /// of every ring made only of such points is kept, so that the ring has an end.
+    fn chain_points(&self) -> Result<Vec<bool>, NetErrors> {
+        let mut chain_points = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let connected_points = node.connected_points();
+            let mut is_chain_point = connected_points.len() == 2 && !node.has_directed_connections();
+            for connected_point in connected_points {
+                let neighbour = &self.nodes[self.position_or_throws(connected_point)?];
+                is_chain_point = is_chain_point && neighbour.is_connected_to(node.point()) && !neighbour.is_directed_to(node.point());
+            }
+            chain_points.push(is_chain_point);
+        }
+
+        let mut reached = vec![false; self.nodes.len()];
+        for start in 0..self.nodes.len() {
+            if chain_points[start] {
+                continue;
+            }
+            for route in self.routes_from(start, &chain_points)? {
+                for &position in &route.positions {
+                    reached[position] = true;
+                }
+            }
+        }
+        for start in 0..self.nodes.len() {
+            if chain_points[start] && !reached[start] {
+                chain_points[start] = false;
+                for route in self.routes_from(start, &chain_points)? {
+                    for &position in &route.positions {
+                        reached[position] = true;
+                    }
+                }
+            }
+        }
+
+        Ok(chain_points)
+    }
+
+    /// Cheapest route from `start` to every point reachable without going through a point
+    /// that is not in a chain, in the order of the connections of `start`.
+    fn cheapest_routes_from(&self, start: usize, chain_points: &[bool]) -> Result<Vec<ChainRoute>, NetErrors> {
+        let mut cheapest_routes: Vec<ChainRoute> = Vec::new();
+        for route in self.routes_from(start, chain_points)? {
+            if route.end() == start {
+                continue;
+            }
+            match cheapest_routes.iter_mut().find(|cheapest_route| cheapest_route.end() == route.end()) {
+                Some(cheapest_route) => if route.cost < cheapest_route.cost {
+                    *cheapest_route = route;
+                },
+                None => cheapest_routes.push(route)
+            }
+        }
+
+        Ok(cheapest_routes)
+    }
+
+    /// Follows every connection of `start` through the chain points until reaching a point
+    /// outside of a chain.
+    fn routes_from(&self, start: usize, chain_points: &[bool]) -> Result<Vec<ChainRoute>, NetErrors> {
+        let mut routes = Vec::new();
+        for (connected_point, weight) in self.nodes[start].weighted_connected_points() {
+            let mut positions = vec![start, self.position_or_throws(connected_point)?];
+            let mut cost = weight;
+            while chain_points[positions[positions.len() - 1]] {
+                let previous_point = self.nodes[positions[positions.len() - 2]].point();
+                let (next_point, next_weight) = self.nodes[positions[positions.len() - 1]].weighted_connected_points().into_iter()
+                    .find(|&(point, _)| !point.is(previous_point))
+                    .expect("a chain point has a connection besides the one it was reached through");
+                positions.push(self.position_or_throws(next_point)?);
+                cost += next_weight;
+            }
+            let directed = self.nodes[start].is_directed_to(connected_point);
+            routes.push(ChainRoute { positions, cost, directed });
+        }
+
+        Ok(routes)
+    }
+
+    fn path_through_positions(&self, positions: &[usize]) -> Result<Path<T>, NetErrors> {
+        path_through_nodes(positions.iter().map(|&position| &self.nodes[position]))
+    }
+
+    fn position_or_throws(&self, point: &T) -> Result<usize, NetErrors> {
+        self.positions.get(point.id())
+            .cloned()
+            .ok_or_else(|| NetErrors::PointNotFound(point.id().to_string()))
+    }
+
+    fn touch(&mut self) {
+        self.version += 1;
+    }
+
+    /// Connects every node to the points of the ids given to `NodeBuilder::connected_id` that
+    /// have a node in the net.
+    fn resolve_connected_ids(&mut self) {
+        for position in 0..self.nodes.len() {
+            let found_points: HashMap<T::Identifier, T> = self.nodes[position].connected_ids().iter()
+                .filter_map(|id| self.positions.get(id).map(|&found_position| (id.clone(), self.nodes[found_position].point().clone())))
+                .collect();
+            if !found_points.is_empty() {
+                self.nodes[position].resolve_connected_ids(|id| found_points.get(id).cloned());
+            }
+        }
+    }
+
+    fn reindex(&mut self) {
+        self.positions = self.nodes.iter()
+            .enumerate()
+            .map(|(position, node)| (node.point().id().clone(), position))
+            .collect();
+    }
+
+    /// Whether the node of `to` comes before `position` and declares an undirected connection
+    /// back to `from`.
+    fn is_declared_back_before(&self, position: usize, from: &T, to: &T) -> bool {
+        self.nodes[..position].iter()
+            .any(|node| node.point_is(to) && node.is_connected_to(from) && !node.is_directed_to(from))
+    }
+
+    fn position_or_insert(&mut self, point: &T) -> usize {
+        match self.position_or_throws(point) {
+            Ok(position) => position,
+            Err(_) => {
+                let node = NodeBuilder::new()
+                    .point(point)
+                    .build()
+                    .unwrap_or_else(|err| panic!("{}", err));
+                self.positions.insert(point.id().clone(), self.nodes.len());
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    pub(crate) fn find_node_or_throws(&self, point: &T) -> Result<&Node<T>, NetErrors> {
+        let position = self.position_or_throws(point)?;
+
+        Ok(&self.nodes[position])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Point> Net<T> {
+    /// Adjacency of the net as a JSON object mapping each point id to the ids of its connected
+    /// points, e.g. `{"A":["B","D"],"B":["A"],"D":["A"]}`. Ids and neighbour lists are sorted.
+    pub fn to_adjacency_json(&self) -> String {
+        let adjacency: BTreeMap<String, Vec<String>> = self.nodes.iter()
+            .map(|node| {
+                let mut neighbours: Vec<String> = node.connected_points().iter()
+                    .map(|point| point.id().to_string())
+                    .collect();
+                neighbours.sort();
+                (node.point().id().to_string(), neighbours)
+            })
+            .collect();
+
+        ::serde_json::to_string(&adjacency).expect("a map of strings is always serializable")
+    }
+
+    /// Builds a net from the JSON produced by `to_adjacency_json`, parsing every id into a point.
+    pub fn from_adjacency_json(json: &str) -> Result<Net<T>, NetErrors> where T: FromStr {
+        let adjacency: BTreeMap<String, Vec<String>> = ::serde_json::from_str(json)
+            .map_err(|err| NetErrors::InvalidFormat(err.to_string()))?;
+
+        let parse_point = |id: &String| T::from_str(id)
+            .map_err(|_| NetErrors::InvalidFormat(format!(r#"cannot parse a point from "{}""#, id)));
+
+        let mut nodes = Vec::new();
+        for (id, neighbour_ids) in &adjacency {
+            let mut builder = NodeBuilder::new();
+            builder.point(&parse_point(id)?);
+
+            for neighbour_id in neighbour_ids {
+                if !adjacency.contains_key(neighbour_id) {
+                    return Err(NetErrors::PointNotFound(neighbour_id.clone()));
+                }
+                builder.connected_point(&parse_point(neighbour_id)?);
+            }
+
+            nodes.push(builder.build().map_err(NetErrors::InvalidFormat)?);
+        }
+
+        Ok(Net::new(nodes))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Point + Send + Sync> Net<T> where T::Identifier: Send + Sync {
+    /// Same paths as `find_paths`, in the same order, searching from each connection of the
+    /// origin in parallel.
+    pub fn par_find_paths(&self, origin: &T, destination: &T) -> Result<Vec<Path<T>>, NetErrors> {
+        if origin.is(destination) {
+            return self.find_paths(origin, destination);
+        }
+        let node_from = self.find_node_or_throws(origin)?;
+        self.position_or_throws(destination)?;
+        let beginning_path = PathBuilder::new().point(origin).build()
+            .map_err(NetErrors::PathCannotBeBuilt)?;
+        let followable_points = node_from.weighted_points_not_in_path(&beginning_path)
+            .ok_or_else(|| unreachable_error(origin, destination))?;
+
+        let path_searches = followable_points.into_par_iter()
+            .map(|(point, weight)| self.all_paths_to_destination_following_path_and_continuing_with_point(destination, &beginning_path, point, weight, usize::MAX))
+            .collect::<Result<Vec<Option<Vec<Path<T>>>>, NetErrors>>()?;
+
+        let paths: Vec<Path<T>> = path_searches.into_iter()
+            .flatten()
+            .flatten()
+            .collect();
+
+        sorted_paths_or_unreachable(paths, origin, destination)
+    }
+}
+
+/// Builds a `Net` from its edges, creating one node per distinct point id (in the order the
+/// points were first given) and declaring each connection on the right nodes.
+#[derive(Debug)]
+pub struct NetBuilder<T: Point> {
+    points: Vec<T>,
+    connections: Vec<(usize, usize, bool)>,
+    conflicting_point: Option<String>,
+}
+
+impl<T: Point + PartialEq> Default for NetBuilder<T> {
+    fn default() -> NetBuilder<T> {
+        NetBuilder::new()
+    }
+}
+
+impl<T: Point + PartialEq> NetBuilder<T> {
+    pub fn new() -> NetBuilder<T> {
+        NetBuilder { points: Vec::new(), connections: Vec::new(), conflicting_point: None }
+    }
+
+    pub fn point(&mut self, point: &T) -> &mut Self {
+        self.position_of(point);
+        self
+    }
+
+    pub fn edge(&mut self, a: &T, b: &T) -> &mut Self {
+        let a_position = self.position_of(a);
+        let b_position = self.position_of(b);
+        self.connections.push((a_position, b_position, false));
+        self.connections.push((b_position, a_position, false));
+        self
+    }
+
+    pub fn directed_edge(&mut self, from: &T, to: &T) -> &mut Self {
+        let from_position = self.position_of(from);
+        let to_position = self.position_of(to);
+        self.connections.push((from_position, to_position, true));
+        self
+    }
+
+    /// Fails with `ConflictingPoint` if two different points were given with the same id.
+    pub fn build(&self) -> Result<Net<T>, NetErrors> {
+        if let Some(ref id) = self.conflicting_point {
+            return Err(NetErrors::ConflictingPoint(id.clone()));
+        }
+
+        let mut builders: Vec<NodeBuilder<T>> = self.points.iter()
+            .map(|point| {
+                let mut builder = NodeBuilder::new();
+                builder.point(point);
+                builder
+            })
+            .collect();
+
+        for &(from, to, directed) in &self.connections {
+            if directed {
+                builders[from].directed_connected_point(&self.points[to]);
+            } else {
+                builders[from].connected_point(&self.points[to]);
+            }
+        }
+
+        let nodes = builders.iter()
+            .map(|builder| builder.build().map_err(NetErrors::NodeCannotBeBuilt))
+            .collect::<Result<Vec<Node<T>>, NetErrors>>()?;
+
+        let net = Net::new(nodes);
+        net.validate()?;
+        Ok(net)
+    }
+
+    fn position_of(&mut self, point: &T) -> usize {
+        match self.points.iter().position(|added_point| added_point.is(point)) {
+            Some(position) => {
+                if self.points[position] != *point && self.conflicting_point.is_none() {
+                    self.conflicting_point = Some(point.id().to_string());
+                }
+                position
+            },
+            None => {
+                self.points.push(point.clone());
+                self.points.len() - 1
+            }
+        }
+    }
+}
+
+/// How `Net::find_paths_with_options` and `Net::find_walks_with_options` walk the net.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    iterative: bool,
+    allow_passthrough_destination: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> SearchOptions {
+        SearchOptions::new()
+    }
+}
+
+impl SearchOptions {
+    pub fn new() -> SearchOptions {
+        SearchOptions { iterative: true, allow_passthrough_destination: true }
+    }
+
+    /// Whether to walk the net with an explicit stack, as `iter_paths` does, rather than
+    /// recursively. Both find the same paths, but the recursive search may overflow the stack
+    /// on large nets. Defaults to `true`.
+    pub fn iterative(&mut self, iterative: bool) -> &mut Self {
+        self.iterative = iterative;
+        self
+    }
+
+    /// Whether a walk reaching the destination may go on and come back to it later, as in
+    /// `A-B-C-B` from `A` to `B`. When `false`, every walk ends at its first arrival at the
+    /// destination, so only `A-B` is kept there and the walk from a point to itself is that
+    /// point alone. Simple paths never revisit a point, so this only changes walks. Defaults to
+    /// `true`.
+    pub fn allow_passthrough_destination(&mut self, allow: bool) -> &mut Self {
+        self.allow_passthrough_destination = allow;
+        self
+    }
+}
+
+/// Iterator over the simple paths between two points, see `Net::iter_paths`. It walks the net
+/// depth first with an explicit stack, building each path only when it reaches the destination.
+pub struct PathIterator<'a, T: Point + 'a> {
+    net: &'a Net<T>,
+    connections: Vec<Vec<usize>>,
+    destination_position: usize,
+    in_path: Vec<bool>,
+    current_path: Vec<usize>,
+    next_connections: Vec<usize>,
+    expansions_left: usize,
+    budget_exceeded: bool,
+    /// Set when the origin is the destination, whose only path is the origin on its own.
+    trivial_path_pending: bool,
+}
+
+impl<'a, T: Point> Iterator for PathIterator<'a, T> {
+    type Item = Path<T>;
+
+    fn next(&mut self) -> Option<Path<T>> {
+        if self.trivial_path_pending {
+            self.trivial_path_pending = false;
+            let path = self.net.path_through_positions(&self.current_path);
+            self.current_path.clear();
+            return path.ok();
+        }
+
+        while let Some(&position) = self.current_path.last() {
+            let next_connection = self.next_connections.last_mut().unwrap();
+
+            if *next_connection == self.connections[position].len() {
+                self.in_path[position] = false;
+                self.current_path.pop();
+                self.next_connections.pop();
+                continue;
+            }
+
+            let next_position = self.connections[position][*next_connection];
+            *next_connection += 1;
+
+            if self.in_path[next_position] {
+                continue;
+            }
+
+            if next_position == self.destination_position {
+                self.current_path.push(next_position);
+                let path = self.net.path_through_positions(&self.current_path);
+                self.current_path.pop();
+                return path.ok();
+            }
+
+            if self.expansions_left == 0 {
+                self.budget_exceeded = true;
+                self.current_path.clear();
+                return None;
+            }
+
+            self.expansions_left -= 1;
+            self.in_path[next_position] = true;
+            self.current_path.push(next_position);
+            self.next_connections.push(0);
+        }
+
+        None
+    }
+}
+
+pub(crate) fn path_through_nodes<'a, T: Point + 'a, I: Iterator<Item = &'a Node<T>>>(nodes: I) -> Result<Path<T>, NetErrors> {
+    let mut builder = PathBuilder::new();
+    let mut previous_node: Option<&Node<T>> = None;
+
+    for node in nodes {
+        match previous_node {
+            None => builder.point(node.point()),
+            Some(previous_node) => match previous_node.weight_to(node.point()) {
+                Some(weight) => builder.point_with_weight(node.point(), weight),
+                None => return Err(NetErrors::PathCannotBeBuilt(format!(
+                    r#"point "{}" is not connected to "{}""#, previous_node.point().id().to_string(), node.point().id().to_string())))
+            }
+        };
+        previous_node = Some(node);
+    }
+
+    builder.build().map_err(NetErrors::PathCannotBeBuilt)
+}
+
+#[derive(Debug, PartialEq)]
+struct Candidate {
+    priority: f64,
+    cost: f64,
+    hops: usize,
+    position: usize,
+}
+
+impl Candidate {
+    fn is_cheaper_than(&self, (cost, hops): (f64, usize)) -> bool {
+        self.cost < cost || (self.cost == cost && self.hops < hops)
+    }
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        other.priority.partial_cmp(&self.priority)
+            .unwrap_or(Ordering::Equal)
+            .then(other.hops.cmp(&self.hops))
+            .then(other.position.cmp(&self.position))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Iterator over the paths of a net from the cheapest one, see `Net::iter_paths_by_cost`.
+pub struct PathsByCost<'a, T: Point + 'a> {
+    net: &'a Net<T>,
+    connections: WeightedConnections,
+    destination_position: usize,
+    pending: BinaryHeap<PartialPath>,
+}
+
+impl<'a, T: Point> Iterator for PathsByCost<'a, T> {
+    type Item = (Path<T>, f64);
+
+    fn next(&mut self) -> Option<(Path<T>, f64)> {
+        while let Some(PartialPath { cost, positions }) = self.pending.pop() {
+            let last_position = *positions.last().unwrap();
+            if last_position == self.destination_position {
+                return self.net.path_through_positions(&positions).ok().map(|path| (path, cost));
+            }
+
+            for &(next_position, weight) in &self.connections[last_position] {
+                if !positions.contains(&next_position) {
+                    let mut next_positions = positions.clone();
+                    next_positions.push(next_position);
+                    self.pending.push(PartialPath { cost: cost + weight, positions: next_positions });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Path being extended by `PathsByCost`, ordered so that the cheapest one is popped first.
+#[derive(Debug, PartialEq)]
+struct PartialPath {
+    cost: f64,
+    positions: Vec<usize>,
+}
+
+impl Eq for PartialPath {}
+
+impl Ord for PartialPath {
+    fn cmp(&self, other: &PartialPath) -> Ordering {
+        other.cost.partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+            .then(other.positions.len().cmp(&self.positions.len()))
+            .then(other.positions.cmp(&self.positions))
+    }
+}
+
+impl PartialOrd for PartialPath {
+    fn partial_cmp(&self, other: &PartialPath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Position and weight of the connections of every point, indexed by position.
+type WeightedConnections = Vec<Vec<(usize, f64)>>;
+
+/// Most waypoints `Net::shortest_tour` accepts, since the memory it needs doubles with each one.
+pub const MAX_TOUR_WAYPOINTS: usize = 16;
+
+type PathCache<T> = HashMap<(<T as Point>::Identifier, <T as Point>::Identifier), Vec<Path<T>>>;
+
+/// One side of a bidirectional Dijkstra search: the best known cost and previous position of
+/// every point, plus the points still to be expanded.
+struct DijkstraFrontier {
+    costs: Vec<Option<f64>>,
+    previous: Vec<Option<usize>>,
+    pending: BinaryHeap<Candidate>,
+}
+
+impl DijkstraFrontier {
+    fn new(size: usize, start: usize) -> DijkstraFrontier {
+        let mut costs = vec![None; size];
+        costs[start] = Some(0.0);
+        let mut pending = BinaryHeap::new();
+        pending.push(Candidate { priority: 0.0, cost: 0.0, hops: 0, position: start });
+
+        DijkstraFrontier { costs, previous: vec![None; size], pending }
+    }
+
+    /// Cost of the next point to expand, skipping the outdated ones.
+    fn next_cost(&mut self) -> f64 {
+        while let Some(candidate) = self.pending.peek() {
+            if self.costs[candidate.position] == Some(candidate.cost) {
+                return candidate.cost;
+            }
+            self.pending.pop();
+        }
+
+        f64::INFINITY
+    }
+
+    /// Expands the next point and returns the positions whose cost improved.
+    fn expand(&mut self, connections: &[Vec<(usize, f64)>]) -> Vec<usize> {
+        let Candidate { cost, hops, position, .. } = match self.pending.pop() {
+            Some(candidate) => candidate,
+            None => return Vec::new()
+        };
+
+        let mut improved = Vec::new();
+        for &(next_position, weight) in &connections[position] {
+            let next_cost = cost + weight;
+            if self.costs[next_position].is_none_or(|current| next_cost < current) {
+                self.costs[next_position] = Some(next_cost);
+                self.previous[next_position] = Some(position);
+                self.pending.push(Candidate { priority: next_cost, cost: next_cost, hops: hops + 1, position: next_position });
+                improved.push(next_position);
+            }
+        }
+
+        improved
+    }
+}
+
+/// Route from a point through a chain of points, ending at the first point not in a chain.
+struct ChainRoute {
+    positions: Vec<usize>,
+    cost: f64,
+    directed: bool,
+}
+
+impl ChainRoute {
+    fn end(&self) -> usize {
+        self.positions[self.positions.len() - 1]
+    }
+}
+
+struct HopDistances {
+    distances: Vec<Option<usize>>,
+    previous: Vec<Option<usize>>,
+}
+
+/// SplitMix64 generator, enough to shuffle connections reproducibly from a seed.
+struct SeededRandom {
+    state: u64,
+}
+
+impl SeededRandom {
+    fn new(seed: u64) -> SeededRandom {
+        SeededRandom { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut mixed = self.state;
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        mixed ^ (mixed >> 31)
+    }
+
+    /// Number in `0..bound`, which must not be empty.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    /// Up to `count` distinct numbers of `0..bound` in random order. Fewer than `bound` numbers
+    /// are drawn with Floyd's algorithm, which takes O(count²) steps however large `bound` is.
+    fn distinct_below(&mut self, bound: usize, count: usize) -> Vec<usize> {
+        let mut numbers: Vec<usize> = if count >= bound {
+            (0..bound).collect()
+        } else {
+            let mut numbers = Vec::with_capacity(count);
+            for upper in bound - count..bound {
+                let number = self.below(upper + 1);
+                numbers.push(if numbers.contains(&number) { upper } else { number });
+            }
+            numbers
+        };
+
+        for last in (1..numbers.len()).rev() {
+            let other = self.below(last + 1);
+            numbers.swap(last, other);
+        }
+
+        numbers
+    }
+}
+
+/// Root of the set containing `position` in a union-find forest, compressing the path to it.
+fn root_of(parents: &mut [usize], position: usize) -> usize {
+    let mut root = position;
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    let mut current = position;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+
+    root
+}
+
+fn unreachable_error<T: Point>(origin: &T, destination: &T) -> NetErrors {
+    NetErrors::DestinationUnreachable(origin.id().to_string(), destination.id().to_string())
+}
+
+/// Paths sorted the way `find_paths` returns them, or `DestinationUnreachable` if there is none.
+pub(crate) fn sorted_paths_or_unreachable<T: Point>(mut paths: Vec<Path<T>>, origin: &T, destination: &T) -> Result<Vec<Path<T>>, NetErrors> {
+    if paths.is_empty() {
+        Err(unreachable_error(origin, destination))
+    } else {
+        paths.sort_by(Path::cmp_by_hops_then_ids);
+        Ok(paths)
+    }
+}
+
+fn positions_from_previous(previous: &[Option<usize>], destination_position: usize) -> Vec<usize> {
+    let mut positions = vec![destination_position];
+    let mut position = destination_position;
+
+    while let Some(previous_position) = previous[position] {
+        positions.push(previous_position);
+        position = previous_position;
+    }
+
+    positions.reverse();
+    positions
+}
+
+quick_error! {
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "error", content = "details"))]
+    pub enum NetErrors {
+        PointNotFound(point_id: String) {
+            description("Point does not exists in the net")
+            display(r#"The point with id "{}" could not be found"#, point_id)
+        }
+        DestinationUnreachable(origin_id: String, destination_id: String) {
+            description("No route connects the points")
+            display(r#"No route leads from "{}" to "{}""#, origin_id, destination_id)
+        }
+        NoPathFound {
+            description("No path found between points")
+            display(r#"No path found between points"#)
+        }
+        PathCannotBeBuilt(path_error: String) {
+            description("Path cannot be built")
+            display(r#"Path cannot be built: {}"#, path_error)
+        }
+        NodeCannotBeBuilt(node_error: String) {
+            description("Node cannot be built")
+            display(r#"Node cannot be built: {}"#, node_error)
+        }
+        ConflictingPoint(point_id: String) {
+            description("Different points share the same id")
+            display(r#"Different points share the id "{}""#, point_id)
+        }
+        NegativeCycle {
+            description("Negative cycle reachable from the origin")
+            display(r#"A cycle of negative weight can be reached from the origin"#)
+        }
+        SearchBudgetExceeded(paths_found: Vec<Vec<String>>) {
+            description("Search budget exceeded")
+            display(r#"Search budget exceeded after finding {} paths"#, paths_found.len())
+        }
+        BlockedPoint(point_id: String) {
+            description("Search starts or ends at a blocked point")
+            display(r#"The point with id "{}" is blocked"#, point_id)
+        }
+        DuplicatePoint(point_id: String) {
+            description("Several nodes share the same point id")
+            display(r#"Several nodes share the point id "{}""#, point_id)
+        }
+        DanglingConnection(point_id: String, connected_point_id: String) {
+            description("Connection to a point that is not in the net")
+            display(r#"The point "{}" is connected to "{}", which is not in the net"#, point_id, connected_point_id)
+        }
+        CycleDetected {
+            description("Net contains a cycle")
+            display(r#"The net contains a cycle, so its points cannot be sorted"#)
+        }
+        AsymmetricConnection(point_id: String, connected_point_id: String) {
+            description("Undirected connection without its reverse connection")
+            display(r#"The point "{}" is connected to "{}", which is not connected back"#, point_id, connected_point_id)
+        }
+        TooManyPaths(hard_cap: usize) {
+            description("Too many paths between the points")
+            display(r#"More than {} paths were found between the points"#, hard_cap)
+        }
+        NotAnEdge(point_id: String, next_point_id: String) {
+            description("Consecutive points of a path are not connected")
+            display(r#"The point "{}" is not connected to "{}""#, point_id, next_point_id)
+        }
+        TooManyWaypoints(waypoint_count: usize, max_waypoints: usize) {
+            description("Too many waypoints to order")
+            display(r#"Cannot choose the order of {} waypoints, the limit is {}"#, waypoint_count, max_waypoints)
+        }
+        InvalidFormat(format_error: String) {
+            description("Net cannot be read")
+            display(r#"Net cannot be read: {}"#, format_error)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use net::*;
+    use node::Point;
+    use node::Node;
+    use path::Path;
+    use node::NodeBuilder;
+
+    const A: char = 'A';
+    const B: char = 'B';
+    const C: char = 'C';
+    const D: char = 'D';
+    const E: char = 'E';
+    const F: char = 'F';
+    const G: char = 'G';
+
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    struct SimplePoint {
+        name: char
+    }
+
+    impl Point for SimplePoint {
+        type Identifier = char;
+
+        fn id(&self) -> &char {
+            &self.name
+        }
+    }
+
+    impl ::std::str::FromStr for SimplePoint {
+        type Err = ::std::char::ParseCharError;
+
+        fn from_str(name: &str) -> Result<SimplePoint, Self::Err> {
+            name.parse().map(|name| SimplePoint { name })
+        }
+    }
+
+    // Given this net:
+    // A - B
+    #[test]
+    fn find_paths_from_a_point_not_in_the_net_should_throw_an_exception() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        let node_a = node(point_a, point_b);
+        let node_b = node(point_b, point_a);
+
+        let a_b_net: Net<SimplePoint> = Net::new(vec![node_a, node_b]);
+
+        let paths = a_b_net.find_paths(&point_c, &point_a);
+
+        assert!(paths.is_err(), "Should not be able to find the path from a point that does not exists in the net");
+    }
+
+    // Given this net:
+    // A - B
+    #[test]
+    fn find_paths_to_a_point_not_in_the_net_should_throw_an_exception() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        let node_a = node(point_a, point_b);
+        let node_b = node(point_b, point_a);
+
+        let a_b_net: Net<SimplePoint> = Net::new(vec![node_a, node_b]);
+
+        let paths = a_b_net.find_paths(&point_a, &point_c);
+
+        assert!(paths.is_err(), "Should not be able to find the path to a point that does not exists in the net");
+    }
+
+    // Given this net:
+    // A - B
+    #[test]
+    fn in_a_b_net_find_path_should_find_a_path_from_a_to_b() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+
+        let node_a = node(point_a, point_b);
+        let node_b = node(point_b, point_a);
+
+        let a_b_net: Net<SimplePoint> = Net::new(vec![node_a, node_b]);
+
+        let paths = a_b_net.find_paths(&point_a, &point_b)
+            .expect("Unexpected error while finding path");
+
+        assert_eq!(format_list_of_paths(paths), "A-B", "Found path should be A-B");
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn in_there_is_no_path_from_a_to_b_find_path_should_throw() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+
+        let node_a = non_connected_node(point_a);
+        let node_b = non_connected_node(point_b);
+
+        let a_b_net: Net<SimplePoint> = Net::new(vec![node_a, node_b]);
+
+        let paths = a_b_net.find_paths(&point_a, &point_b);
+
+        match paths {
+            Ok(_) => panic!("should throw an error"),
+            Err(ref err) => {
+                match err {
+                    NetErrors::DestinationUnreachable(origin_id, destination_id) => {
+                        assert_eq!((origin_id.as_str(), destination_id.as_str()), ("A", "B"), "should tell which points are not connected")
+                    },
+                    _ => panic!("DestinationUnreachable exception expected")
+                }
+            }
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_from_a_to_itself_should_be_the_path_made_of_a_alone() {
+        let point_a = simple_point(A);
+        let net = diamond_net();
+
+        let paths = net.find_paths(&point_a, &point_a).expect("should find the trivial path from A to A");
+        let iterated_paths: Vec<Path<SimplePoint>> = net.iter_paths(&point_a, &point_a).expect("should iterate paths from A to A").collect();
+
+        assert_eq!(format_list_of_paths(paths), "A", "should not go around a cycle");
+        assert_eq!(format_paths_with_cost(&iterated_paths), vec!["A (0)"], "should yield the same trivial path");
+        assert_eq!(net.count_paths(&point_a, &point_a).expect("should count paths from A to A"), 1, "should count the trivial path");
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn find_paths_to_a_point_out_of_the_net_should_throw() {
+        let point_a = simple_point(A);
+        let point_e = simple_point(E);
+        let a_b_net: Net<SimplePoint> = Net::new(vec![non_connected_node(point_a), non_connected_node(simple_point(B))]);
+
+        match a_b_net.find_paths(&point_a, &point_e) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "E"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_should_find_a_path_from_a_to_c() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        let node_a = node(point_a, point_b);
+        let node_b = node_connected_to(point_b, vec![point_a, point_c]);
+        let node_c = node(point_c, point_b);
+
+        let a_b_c_net: Net<SimplePoint> = Net::new(vec![node_a, node_b, node_c]);
+
+        let paths = a_b_c_net.find_paths(&point_a, &point_c)
+            .unwrap_or_else(|_| panic!("should not throw exception finding path a to c in net {:?}", a_b_c_net));
+
+        assert_eq!("A-B-C", format_list_of_paths(paths), "found path should be A-B-C");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn in_triangle_net_should_find_two_paths_from_a_to_c() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        let node_a = node_connected_to(point_a, vec![point_b, point_d]);
+        let node_b = node_connected_to(point_b, vec![point_a, point_c]);
+        let node_c = node_connected_to(point_c, vec![point_b, point_d]);
+        let node_d = node_connected_to(point_d, vec![point_a, point_c]);
+
+        let triangle_net: Net<SimplePoint> = Net::new(vec![node_a, node_b, node_c, node_d]);
+
+        let paths = triangle_net.find_paths(&point_a, &point_c)
+            .unwrap_or_else(|_| panic!("should not throw exception finding path a to c in net {:?}", triangle_net));
+
+        let formatted_paths = format_list_of_paths(paths);
+
+        assert_eq!(formatted_paths, "A-B-C + A-D-C", "should find A-B-C and A-D-C paths");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn should_find_all_feasible_paths_from_a_to_c() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        let node_a = node_connected_to(point_a, vec![point_b, point_d]);
+        let node_b = node_connected_to(point_b, vec![point_a, point_c, point_d]);
+        let node_c = node_connected_to(point_c, vec![point_b, point_d]);
+        let node_d = node_connected_to(point_d, vec![point_a, point_c, point_b]);
+
+        let triangle_net: Net<SimplePoint> = Net::new(vec![node_a, node_b, node_c, node_d]);
+
+        let paths = triangle_net.find_paths(&point_a, &point_c)
+            .unwrap_or_else(|_| panic!("should not throw exception finding path a to c in net {:?}", triangle_net));
+
+        let formatted_paths = format_list_of_paths(paths);
+
+        assert_eq!(formatted_paths, "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should find the four feasible paths");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn in_triangle_net_a_and_c_should_be_two_edge_connected() {
+        let triangle_net = triangle_net();
+
+        let two_edge_connected = triangle_net.is_two_edge_connected(&simple_point(A), &simple_point(C))
+            .expect("should not throw checking if A and C are 2-edge-connected");
+
+        assert!(two_edge_connected, "A and C should remain connected after removing any single edge");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_a_and_c_should_not_be_two_edge_connected() {
+        let a_b_c_net = a_b_c_net();
+
+        let two_edge_connected = a_b_c_net.is_two_edge_connected(&simple_point(A), &simple_point(C))
+            .expect("should not throw checking if A and C are 2-edge-connected");
+
+        assert!(!two_edge_connected, "removing A-B or B-C should disconnect A and C");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_all_points_should_be_reachable_from_a() {
+        let a_b_c_net = a_b_c_net();
+
+        let reachable = a_b_c_net.reachable_from(&simple_point(A))
+            .expect("should not throw finding points reachable from A");
+
+        let reachable_ids: Vec<char> = reachable.iter().map(|point| *point.id()).collect();
+        assert_eq!(reachable_ids, vec![A, B, C], "A, B and C should be reachable from A");
+    }
+
+    // Given this net of points:
+    // A - B - C    D - E
+    #[test]
+    fn points_of_another_component_should_not_be_reachable() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::from_edges(vec![(points[0], points[1]), (points[1], points[2]), (points[3], points[4])]);
+
+        let reachable = net.reachable_from(&points[4])
+            .expect("should not throw finding points reachable from E");
+
+        let reachable_ids: Vec<char> = reachable.iter().map(|point| *point.id()).collect();
+        assert_eq!(reachable_ids, vec![E, D], "only D should be reachable from E");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn in_triangle_net_edge_list_should_contain_each_edge_once() {
+        let triangle_net = triangle_net();
+
+        let edges = triangle_net.to_edge_list();
+
+        assert_eq!(edges, vec![(A, B), (A, D), (B, C), (C, D)], "should list A-B, A-D, B-C and C-D once");
+    }
+
+    // Given this net of points:
+    //     E   F
+    //      \ /
+    // A  -  B  -  C
+    //  \         /
+    //   \       /
+    //       D
+    #[test]
+    fn find_path_min_max_degree_should_avoid_the_hub() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let point_e = simple_point(E);
+        let point_f = simple_point(F);
+
+        let net: Net<SimplePoint> = Net::new(vec![
+            node_connected_to(point_a, vec![point_b, point_d]),
+            node_connected_to(point_b, vec![point_a, point_c, point_e, point_f]),
+            node_connected_to(point_c, vec![point_b, point_d]),
+            node_connected_to(point_d, vec![point_a, point_c]),
+            node(point_e, point_b),
+            node(point_f, point_b),
+        ]);
+
+        let path = net.find_path_min_max_degree(&point_a, &point_c)
+            .expect("should not throw finding the least congested path from A to C");
+
+        assert_eq!(format_path_kebab(&path), "A-D-C", "should go through D instead of the hub B");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn count_paths_should_count_the_paths_find_paths_returns() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let diamond_net = diamond_net();
+        let complete_net = complete_net();
+
+        assert_eq!(diamond_net.count_paths(&point_a, &point_c).expect("should count paths from A to C"), 4, "should count the four feasible paths");
+        assert_eq!(
+            complete_net.count_paths(&point_a, &point_c).expect("should count paths from A to C"),
+            complete_net.find_paths(&point_a, &point_c).expect("should find paths from A to C").len(),
+            "should count every path of the complete net"
+        );
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn capped_search_should_throw_when_there_are_more_paths_than_the_cap() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        match net.find_paths_capped(&point_a, &point_c, 3) {
+            Err(NetErrors::TooManyPaths(hard_cap)) => assert_eq!(hard_cap, 3),
+            _ => panic!("TooManyPaths error expected")
+        }
+        let paths = net.find_paths_capped(&point_a, &point_c, 4).expect("should find the four paths");
+        assert_eq!(paths, net.find_paths(&point_a, &point_c).unwrap(), "should find the same paths as find_paths, in the same order");
+    }
+
+    // Given this net of points, where every point is connected to every other:
+    // A - B
+    // | X |
+    // D - C
+    #[test]
+    fn in_complete_net_every_clustering_coefficient_should_be_one() {
+        let complete_net = complete_net();
+
+        for point in &[A, B, C, D] {
+            let coefficient = complete_net.clustering_coefficient(&simple_point(*point))
+                .expect("should not throw computing the clustering coefficient");
+            assert_eq!(coefficient, 1.0, "all neighbours of {} should be connected to each other", point);
+        }
+
+        assert_eq!(complete_net.average_clustering(), 1.0, "average clustering should be 1");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_clustering_coefficient_of_b_should_be_zero() {
+        let a_b_c_net = a_b_c_net();
+
+        let coefficient = a_b_c_net.clustering_coefficient(&simple_point(B))
+            .expect("should not throw computing the clustering coefficient");
+
+        assert_eq!(coefficient, 0.0, "A and C are not connected to each other");
+    }
+
+    // Given this net of points, where A is also connected to X, which is not in the net:
+    // A - B
+    //  \ /
+    //   C
+    #[test]
+    fn a_point_with_a_dangling_connection_should_count_as_zero_in_the_average_clustering() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node_connected_to(point_a, vec![point_b, point_c, simple_point('X')]),
+            node_connected_to(point_b, vec![point_a, point_c]),
+            node_connected_to(point_c, vec![point_a, point_b]),
+        ]);
+
+        match net.clustering_coefficient(&point_a) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "X"),
+            _ => panic!("PointNotFound error expected")
+        }
+        assert_eq!(net.average_clustering(), 2.0 / 3.0, "A should count as zero, B and C as one");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn in_triangle_net_there_should_be_a_single_cycle() {
+        let triangle_net = triangle_net();
+
+        let cycles = triangle_net.simple_cycles()
+            .expect("should not throw finding cycles");
+
+        assert_eq!(format_list_of_paths(cycles), "A-B-C-D-A", "should only find the A-B-C-D cycle");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn in_diamond_net_should_find_the_three_cycles() {
+        let diamond_net = diamond_net();
+
+        let cycles = diamond_net.simple_cycles()
+            .expect("should not throw finding cycles");
+
+        assert_eq!(format_list_of_paths(cycles), "A-B-C-D-A + A-B-D-A + B-C-D-B", "should find A-B-C-D, A-B-D and B-C-D cycles");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_there_should_be_no_cycles() {
+        let a_b_c_net = a_b_c_net();
+
+        let cycles = a_b_c_net.simple_cycles()
+            .expect("should not throw finding cycles");
+
+        assert!(cycles.is_empty(), "a linear net has no cycles");
+    }
+
+    // Given this net of points:
+    // A -> C -> B -> A
+    #[test]
+    fn directed_cycle_should_be_found_in_its_own_direction() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            directed_node(point_a, vec![point_c]),
+            directed_node(point_b, vec![point_a]),
+            directed_node(point_c, vec![point_b]),
+        ]);
+
+        let cycles = net.simple_cycles()
+            .expect("should not throw finding cycles");
+
+        assert_eq!(format_list_of_paths(cycles), "A-C-B-A", "should find A -> C -> B -> A");
+    }
+
+    // Given this net of points:
+    // A -> B -> A
+    #[test]
+    fn two_opposite_directed_connections_should_make_a_cycle() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net = Net::new(vec![
+            directed_node(point_a, vec![point_b]),
+            directed_node(point_b, vec![point_a]),
+        ]);
+
+        let cycles = net.simple_cycles()
+            .expect("should not throw finding cycles");
+
+        assert_eq!(format_list_of_paths(cycles), "A-B-A", "should find A -> B -> A");
+    }
+
+    // Given this net of points, where every point is connected to every other:
+    // A - B
+    // | X |
+    // D - C
+    #[test]
+    fn in_complete_net_all_points_should_have_a_degree_centrality_of_one() {
+        let complete_net = complete_net();
+
+        let centralities = complete_net.degree_centrality();
+
+        assert_eq!(centralities, vec![(A, 1.0), (B, 1.0), (C, 1.0), (D, 1.0)], "all points should tie at 1");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_b_should_have_the_highest_degree_centrality() {
+        let a_b_c_net = a_b_c_net();
+
+        let centralities = a_b_c_net.degree_centrality();
+
+        assert_eq!(centralities, vec![(B, 1.0), (A, 0.5), (C, 0.5)], "B should rank first");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn indexed_net_should_find_the_same_paths_as_the_net() {
+        let diamond_net = diamond_net();
+
+        let (indexed_net, ids) = diamond_net.to_indexed()
+            .expect("should not throw indexing the net");
+        let paths = indexed_net.find_paths(0, 2)
+            .expect("should not throw finding paths in the indexed net");
+
+        let mut formatted_paths: Vec<String> = paths.iter()
+            .map(|path| path.iter().map(|&index| ids[index as usize].to_string()).collect::<Vec<String>>().join("-"))
+            .collect();
+        formatted_paths.sort();
+
+        assert_eq!(ids, vec![A, B, C, D], "indices should follow the order of the nodes");
+        assert_eq!(formatted_paths.join(" + "), "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should find the four feasible paths");
+    }
+
+    // Given this net of points:
+    // A - B - C   D
+    #[test]
+    fn isolated_point_should_not_reach_the_destination() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        let net: Net<SimplePoint> = Net::new(vec![
+            node(point_a, point_b),
+            node_connected_to(point_b, vec![point_a, point_c]),
+            node(point_c, point_b),
+            non_connected_node(point_d),
+        ]);
+
+        let stranded = net.cannot_reach(&point_c)
+            .expect("should not throw finding points that cannot reach C");
+
+        assert_eq!(stranded, vec![D], "only D should be unable to reach C");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn cannot_reach_a_point_not_in_the_net_should_throw() {
+        let a_b_c_net = a_b_c_net();
+
+        match a_b_c_net.cannot_reach(&simple_point(D)) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "D"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_an_a_b_c_net_average_degree_should_be_four_thirds() {
+        let a_b_c_net = a_b_c_net();
+
+        assert_eq!(a_b_c_net.average_degree(), 4.0 / 3.0, "two edges over three points");
+    }
+
+    // Given this net of points, where every point is connected to every other:
+    // A - B
+    // | X |
+    // D - C
+    #[test]
+    fn in_complete_net_average_degree_should_be_three() {
+        let complete_net = complete_net();
+
+        assert_eq!(complete_net.average_degree(), 3.0, "every point is connected to the other three");
+    }
+
+    // Given this net of points, where A - B is expensive:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn find_path_min_expensive_should_steer_around_the_expensive_edge() {
+        let triangle_net = triangle_net();
+        let is_a_b = |from: &SimplePoint, to: &SimplePoint| {
+            (from.name == A && to.name == B) || (from.name == B && to.name == A)
+        };
+
+        let path = triangle_net.find_path_min_expensive(&simple_point(A), &simple_point(C), is_a_b)
+            .expect("should not throw finding the path with fewest expensive edges");
+
+        assert_eq!(format_path_kebab(&path), "A-D-C", "should avoid the expensive A-B edge");
+    }
+
+    // Given this net of points, where A - B is expensive:
+    // A - B - C
+    #[test]
+    fn find_path_min_expensive_should_cross_an_unavoidable_expensive_edge() {
+        let a_b_c_net = a_b_c_net();
+        let is_a_b = |from: &SimplePoint, to: &SimplePoint| {
+            (from.name == A && to.name == B) || (from.name == B && to.name == A)
+        };
+
+        let path = a_b_c_net.find_path_min_expensive(&simple_point(A), &simple_point(C), is_a_b)
+            .expect("should not throw finding the path with fewest expensive edges");
+
+        assert_eq!(format_path_kebab(&path), "A-B-C", "A-B-C is the only path");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[cfg(feature = "serde")]
+    #[test]
+    fn triangle_net_should_round_trip_through_adjacency_json() {
+        let triangle_net = triangle_net();
+
+        let json = triangle_net.to_adjacency_json();
+        let read_net: Net<SimplePoint> = Net::from_adjacency_json(&json)
+            .expect("should read the net back from its adjacency json");
+
+        assert_eq!(json, r#"{"A":["B","D"],"B":["A","C"],"C":["B","D"],"D":["A","C"]}"#, "should map each id to its sorted neighbours");
+        assert_eq!(read_net.to_adjacency_json(), json, "should read back the same adjacency");
+        let paths = read_net.find_paths(&simple_point(A), &simple_point(C))
+            .expect("should find paths in the net read from json");
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-D-C", "should find A-B-C and A-D-C paths");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[cfg(feature = "serde")]
+    #[test]
+    fn triangle_net_should_round_trip_through_serde() {
+        let json = ::serde_json::to_string(&triangle_net()).expect("should serialize the net");
+        let read_net: Net<SimplePoint> = ::serde_json::from_str(&json).expect("should deserialize the net");
+
+        assert_eq!(read_net.nodes, triangle_net().nodes, "should read back the same nodes");
+        assert_eq!(read_net.positions.len(), read_net.nodes.len(), "should index every point");
+        let paths = read_net.find_paths(&simple_point(A), &simple_point(C))
+            .expect("should find paths in the deserialized net");
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-D-C", "should find A-B-C and A-D-C paths");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn path_should_round_trip_through_serde() {
+        let path = weighted_triangle_net(3.0, 1.0).shortest_path(&simple_point(A), &simple_point(C))
+            .expect("should find the shortest path from A to C");
+
+        let json = ::serde_json::to_string(&path).expect("should serialize the path");
+        let read_path: Path<SimplePoint> = ::serde_json::from_str(&json).expect("should deserialize the path");
+
+        assert_eq!(format_path_kebab(&read_path), "A-D-C", "should keep the points");
+        assert_eq!(read_path.cost(), 2.0, "should keep the weights");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn adjacency_json_with_an_unknown_neighbour_should_throw() {
+        let read_net: Result<Net<SimplePoint>, NetErrors> = Net::from_adjacency_json(r#"{"A":["B"]}"#);
+
+        match read_net {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "B"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn point_not_found_error_should_round_trip_through_json() {
+        let error = NetErrors::PointNotFound("A".into());
+
+        let json = ::serde_json::to_string(&error).expect("should serialize the error");
+        let deserialized_error: NetErrors = ::serde_json::from_str(&json).expect("should deserialize the error");
+
+        assert_eq!(json, r#"{"error":"PointNotFound","details":"A"}"#, "should tag the error with its variant");
+        match deserialized_error {
+            NetErrors::PointNotFound(id) => assert_eq!(id, "A"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C - D - E
+    #[test]
+    fn shortest_tour_should_visit_the_waypoints_in_the_best_order() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let line_net: Net<SimplePoint> = Net::new(vec![
+            node(points[0], points[1]),
+            node_connected_to(points[1], vec![points[0], points[2]]),
+            node_connected_to(points[2], vec![points[1], points[3]]),
+            node_connected_to(points[3], vec![points[2], points[4]]),
+            node(points[4], points[3]),
+        ]);
+
+        let tour = line_net.shortest_tour(&points[0], &[&points[3], &points[1], &points[2]], &points[4])
+            .expect("should not throw finding a tour through D, B and C");
+
+        assert_eq!(tour.to_string(), "A-B-C-D-E", "should visit B, C and D in order along the line");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //     |
+    //     D
+    #[test]
+    fn shortest_tour_in_a_tree_should_go_back_through_the_branching_point() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let tree_net = Net::new(vec![
+            node(point_a, point_b),
+            node_connected_to(point_b, vec![point_a, point_c, point_d]),
+            node(point_c, point_b),
+            node(point_d, point_b),
+        ]);
+
+        let tour = tree_net.shortest_tour(&point_a, &[&point_d, &point_c], &point_a)
+            .expect("should not throw finding a tour through D and C");
+
+        assert_eq!(tour.to_string(), "A-B-C-B-D-B-A", "should go through B three times");
+        assert_eq!(tour.cost(), 6.0, "should follow six connections");
+    }
+
+    // Given this net of points, where A-B weighs 10 and every other connection 1:
+    // A ------ B
+    // |        |
+    // D - E -- C
+    #[test]
+    fn shortest_tour_without_waypoints_should_take_the_cheapest_route() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            weighted_node(points[0], vec![(points[1], 10.0), (points[3], 1.0)]),
+            weighted_node(points[1], vec![(points[0], 10.0), (points[2], 1.0)]),
+            weighted_node(points[2], vec![(points[1], 1.0), (points[4], 1.0)]),
+            weighted_node(points[3], vec![(points[0], 1.0), (points[4], 1.0)]),
+            weighted_node(points[4], vec![(points[2], 1.0), (points[3], 1.0)]),
+        ]);
+
+        let tour = net.shortest_tour(&points[0], &[], &points[1]).expect("should find a tour from A to B");
+
+        assert_eq!(tour.to_string(), "A-D-E-C-B", "should go around the heavy connection");
+        assert_eq!(tour.cost(), 4.0, "should cost as much as the shortest path");
+    }
+
+    #[test]
+    fn shortest_tour_through_too_many_waypoints_should_throw() {
+        let points: Vec<SimplePoint> = ('a'..='z').map(simple_point).collect();
+        let net = Net::from_edges(points.windows(2).map(|pair| (pair[0], pair[1])));
+        let waypoints: Vec<&SimplePoint> = points[1..points.len() - 1].iter().collect();
+
+        match net.shortest_tour(&points[0], &waypoints, &points[points.len() - 1]) {
+            Err(NetErrors::TooManyWaypoints(24, MAX_TOUR_WAYPOINTS)) => (),
+            _ => panic!("TooManyWaypoints error expected")
+        }
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn shortest_tour_through_an_unreachable_waypoint_should_throw() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net: Net<SimplePoint> = Net::new(vec![non_connected_node(point_a), non_connected_node(point_b)]);
+
+        match net.shortest_tour(&point_a, &[&point_b], &point_a) {
+            Err(NetErrors::DestinationUnreachable(ref origin_id, ref destination_id)) if origin_id == "A" && destination_id == "B" => (),
+            _ => panic!("DestinationUnreachable error from A to B expected")
+        }
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn in_a_net_of_two_isolated_points_one_edge_should_connect_them() {
+        let net: Net<SimplePoint> = Net::new(vec![non_connected_node(simple_point(A)), non_connected_node(simple_point(B))]);
+
+        let edges_to_connect = net.edges_to_connect()
+            .expect("should not throw counting the edges to connect the net");
+
+        assert_eq!(edges_to_connect, 1, "a single A-B edge should connect the net");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn in_a_connected_net_no_edge_should_be_needed() {
+        let a_b_c_net = a_b_c_net();
+
+        let edges_to_connect = a_b_c_net.edges_to_connect()
+            .expect("should not throw counting the edges to connect the net");
+
+        assert_eq!(edges_to_connect, 0, "the net is already connected");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_from_a_to_c_should_be_grouped_by_the_point_before_c() {
+        let diamond_net = diamond_net();
+
+        let mut paths_by_last_edge = diamond_net.find_paths_by_last_edge(&simple_point(A), &simple_point(C))
+            .expect("should not throw grouping paths from A to C");
+
+        assert_eq!(paths_by_last_edge.len(), 2, "paths should only enter C from B or D");
+        let through_b = paths_by_last_edge.remove(&B).expect("some paths should enter C from B");
+        let through_d = paths_by_last_edge.remove(&D).expect("some paths should enter C from D");
+        assert_eq!(format_list_of_paths(through_b), "A-B-C + A-D-B-C", "should group paths ending with B-C");
+        assert_eq!(format_list_of_paths(through_d), "A-B-D-C + A-D-C", "should group paths ending with D-C");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn path_from_a_to_itself_should_be_grouped_under_a() {
+        let diamond_net = diamond_net();
+
+        let mut paths_by_last_edge = diamond_net.find_paths_by_last_edge(&simple_point(A), &simple_point(A))
+            .expect("should not throw grouping paths from A to A");
+
+        assert_eq!(paths_by_last_edge.len(), 1, "there should only be the trivial path");
+        assert_eq!(format_list_of_paths(paths_by_last_edge.remove(&A).expect("the trivial path should be under A")), "A", "should keep the trivial path");
+    }
+
+    // Given this net of points, with weights:
+    // A -1- B -1- C
+    //  \         /
+    //   5       1
+    //    \     /
+    //       D
+    #[test]
+    fn find_cheapest_path_should_return_the_path_with_the_lowest_cost() {
+        let net = weighted_triangle_net(1.0, 5.0);
+
+        let path = net.find_cheapest_path(&simple_point(A), &simple_point(C))
+            .expect("should not throw finding the cheapest path from A to C");
+
+        assert_eq!(format_path_kebab(&path), "A-B-C", "A-B-C costs 2 and A-D-C costs 6");
+        assert_eq!(path.cost(), 2.0, "should carry the weights of its connections");
+    }
+
+    // Given this net of points, with weights:
+    // A -1- B -2- C
+    //  \         /
+    //   1       1
+    //    \     /
+    //       D
+    #[test]
+    fn find_cheapest_path_should_prefer_fewer_hops_on_a_tie() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let point_e = simple_point(E);
+
+        let net: Net<SimplePoint> = Net::new(vec![
+            weighted_node(point_a, vec![(point_b, 1.0), (point_e, 1.0)]),
+            weighted_node(point_b, vec![(point_a, 1.0), (point_c, 2.0)]),
+            weighted_node(point_c, vec![(point_b, 2.0), (point_d, 1.0)]),
+            weighted_node(point_d, vec![(point_e, 1.0), (point_c, 1.0)]),
+            weighted_node(point_e, vec![(point_a, 1.0), (point_d, 1.0)]),
+        ]);
+
+        let path = net.find_cheapest_path(&point_a, &point_c)
+            .expect("should not throw finding the cheapest path from A to C");
+
+        assert_eq!(format_path_kebab(&path), "A-B-C", "A-B-C and A-E-D-C both cost 3 but A-B-C has fewer hops");
+    }
+
+    // Given this net of points, with weights:
+    // A -1- B -1- C
+    //  \         /
+    //   5       1
+    //    \     /
+    //       D
+    #[test]
+    fn shortest_path_should_return_the_path_with_the_lowest_cost() {
+        let net = weighted_triangle_net(1.0, 5.0);
+
+        let path = net.shortest_path(&simple_point(A), &simple_point(C))
+            .expect("should not throw finding the shortest path from A to C");
+
+        assert_eq!(format_path_kebab(&path), "A-B-C", "A-B-C costs 2 and A-D-C costs 6");
+        assert_eq!(path.cost(), 2.0, "should carry the weights of its connections");
+    }
+
+    // Given this net of points, with weights:
+    // A -1- B -9- C
+    //  \         /
+    //   1       1
+    //    \     /
+    //       D
+    #[test]
+    fn shortest_path_should_match_find_cheapest_path() {
+        let net = weighted_triangle_net(9.0, 1.0);
+
+        let shortest_path = net.shortest_path(&simple_point(A), &simple_point(C))
+            .expect("should not throw finding the shortest path from A to C");
+        let cheapest_path = net.find_cheapest_path(&simple_point(A), &simple_point(C))
+            .expect("should not throw finding the cheapest path from A to C");
+
+        assert_eq!(format_path_kebab(&shortest_path), "A-D-C", "A-D-C costs 2 and A-B-C costs 10");
+        assert_eq!(format_path_kebab(&shortest_path), format_path_kebab(&cheapest_path), "both searches should agree");
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn shortest_path_between_disconnected_points_should_throw() {
+        let net: Net<SimplePoint> = Net::new(vec![non_connected_node(simple_point(A)), non_connected_node(simple_point(B))]);
+
+        match net.shortest_path(&simple_point(A), &simple_point(B)) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net:
+    // A - B
+    #[test]
+    fn shortest_path_to_a_point_not_in_the_net_should_throw() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net: Net<SimplePoint> = Net::new(vec![node(point_a, point_b), node(point_b, point_a)]);
+
+        match net.shortest_path(&point_a, &simple_point(C)) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "C"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points, where B-C weighs 3 and A-D weighs 2:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn path_cost_should_sum_the_weights_of_the_net() {
+        let net = weighted_triangle_net(3.0, 2.0);
+        let path = Path::try_from_points(&[&simple_point(A), &simple_point(B), &simple_point(C)]).unwrap();
+
+        let cost = net.path_cost(&path).expect("A-B-C should follow connections of the net");
+
+        assert_eq!(path.cost(), 2.0, "the path itself carries the default weights");
+        assert_eq!(cost, 4.0, "A-B should weigh 1 and B-C 3");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn path_cost_of_a_path_skipping_a_connection_should_throw() {
+        let net = a_b_c_net();
+        let path = Path::try_from_points(&[&simple_point(A), &simple_point(C)]).unwrap();
+
+        match net.path_cost(&path) {
+            Err(NetErrors::NotAnEdge(id, next_id)) => {
+                assert_eq!(id, "A");
+                assert_eq!(next_id, "C");
+            },
+            _ => panic!("NotAnEdge error expected")
+        }
+    }
+
+    // Given this net of points, where going through B costs 10:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn a_high_cost_on_b_should_reroute_the_cheapest_path_through_d() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let triangle_net = triangle_net();
+
+        let (path, cost) = triangle_net.find_cheapest_path_with_node_cost(&point_a, &point_c, |point| if point.name == B { 10.0 } else { 1.0 })
+            .expect("should find the cheapest path from A to C");
+
+        assert_eq!(path.to_string(), "A-D-C", "should avoid B");
+        assert_eq!(cost, 3.0, "two connections and going through D should cost 3");
+    }
+
+    // Given this net of points, with weights:
+    // A -1- B -9- C
+    //  \         /
+    //   1       1
+    //    \     /
+    //       D
+    #[test]
+    fn find_path_astar_with_zero_heuristic_should_match_shortest_path() {
+        let net = weighted_triangle_net(9.0, 1.0);
+
+        let astar_path = net.find_path_astar(&simple_point(A), &simple_point(C), &ZeroHeuristic)
+            .expect("should not throw finding a path with A*");
+        let shortest_path = net.shortest_path(&simple_point(A), &simple_point(C))
+            .expect("should not throw finding the shortest path from A to C");
+
+        assert_eq!(format_path_kebab(&astar_path), format_path_kebab(&shortest_path), "A* without estimates is Dijkstra");
+    }
+
+    // Given this net of points, with weights, and estimates of the remaining cost to C:
+    // A -1- B -9- C      A: 2, B: 3
+    //  \         /       C: 0, D: 1
+    //   1       1
+    //    \     /
+    //       D
+    #[test]
+    fn find_path_astar_should_find_the_cheapest_path_with_an_admissible_heuristic() {
+        struct RemainingCostToC;
+
+        impl Heuristic<SimplePoint> for RemainingCostToC {
+            fn estimate(&self, from: &SimplePoint, _to: &SimplePoint) -> f64 {
+                match from.name {
+                    'A' => 2.0,
+                    'B' => 3.0,
+                    'D' => 1.0,
+                    _ => 0.0
+                }
+            }
+        }
+
+        let net = weighted_triangle_net(9.0, 1.0);
+
+        let path = net.find_path_astar(&simple_point(A), &simple_point(C), &RemainingCostToC)
+            .expect("should not throw finding a path with A*");
+
+        assert_eq!(format_path_kebab(&path), "A-D-C", "A-D-C costs 2 and A-B-C costs 10");
+    }
+
+    // Given this net of points, where B-C weighs 9:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn greedy_search_should_follow_the_heuristic_whatever_the_cost() {
+        struct NamesCloseToC;
+
+        impl Heuristic<SimplePoint> for NamesCloseToC {
+            fn estimate(&self, from: &SimplePoint, to: &SimplePoint) -> f64 {
+                (from.name as i32 - to.name as i32).abs() as f64
+            }
+        }
+
+        let net = weighted_triangle_net(9.0, 1.0);
+
+        let path = net.find_path_greedy(&simple_point(A), &simple_point(C), &NamesCloseToC)
+            .expect("should not throw finding a path greedily");
+
+        assert_eq!((format_path_kebab(&path), path.cost()), (String::from("A-B-C"), 10.0), "B looks closer to C than D does");
+    }
+
+    // Given this net of points, where E is a dead end:
+    // E - A - B - C
+    #[test]
+    fn greedy_search_should_move_on_from_a_dead_end() {
+        struct DeadEndLooksClose;
+
+        impl Heuristic<SimplePoint> for DeadEndLooksClose {
+            fn estimate(&self, from: &SimplePoint, _to: &SimplePoint) -> f64 {
+                match from.name {
+                    'E' => 0.5,
+                    'C' => 0.0,
+                    _ => 1.0
+                }
+            }
+        }
+
+        let point_e = simple_point(E);
+        let mut net = a_b_c_net();
+        net.add_node(non_connected_node(point_e)).expect("should add E");
+        net.connect(&simple_point(A), &point_e).expect("should connect A and E");
+
+        let path = net.find_path_greedy(&simple_point(A), &simple_point(C), &DeadEndLooksClose)
+            .expect("should not throw finding a path greedily");
+
+        assert_eq!(format_path_kebab(&path), "A-B-C", "should go on through B after E");
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn paths_should_only_follow_directed_connections_in_their_direction() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        let net: Net<SimplePoint> = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new()
+                .point(&point_b)
+                .connected_point(&point_a)
+                .directed_connected_point(&point_c)
+                .build()
+                .unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        let paths = net.find_paths(&point_a, &point_c)
+            .expect("should find a path from A to C");
+        assert_eq!(format_list_of_paths(paths), "A-B-C", "should follow B -> C");
+        assert!(net.find_paths(&point_c, &point_a).is_err(), "should not go back through B -> C");
+        assert!(net.is_directed(), "a net with a one-way connection is directed");
+        assert_eq!(net.to_edge_list(), vec![(A, B), (B, C)], "should list A-B once and B -> C");
+    }
+
+    // Given this net of points, where A -> B and B -> A are one-way connections:
+    // A <-> B
+    #[test]
+    fn two_directed_connections_should_make_a_bidirectional_edge() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+
+        let net: Net<SimplePoint> = Net::new(vec![directed_node(point_a, vec![point_b]), directed_node(point_b, vec![point_a])]);
+
+        let paths_from_a = net.find_paths(&point_a, &point_b)
+            .expect("should find a path from A to B");
+        let paths_from_b = net.find_paths(&point_b, &point_a)
+            .expect("should find a path from B to A");
+
+        assert_eq!(format_list_of_paths(paths_from_a), "A-B", "should follow A -> B");
+        assert_eq!(format_list_of_paths(paths_from_b), "B-A", "should follow B -> A");
+        assert_eq!(net.to_edge_list(), vec![(A, B), (B, A)], "should list both one-way connections");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn a_net_without_one_way_connections_should_not_be_directed() {
+        assert!(!a_b_c_net().is_directed(), "A-B-C only has undirected connections");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn iter_paths_should_yield_the_same_paths_as_find_paths() {
+        let diamond_net = diamond_net();
+
+        let paths: Vec<Path<SimplePoint>> = diamond_net.iter_paths(&simple_point(A), &simple_point(C))
+            .expect("should not throw iterating paths from A to C")
+            .collect();
+
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should yield the four feasible paths");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn iter_paths_should_stop_after_the_requested_paths() {
+        let diamond_net = diamond_net();
+        let point_c = simple_point(C);
+
+        let paths: Vec<Path<SimplePoint>> = diamond_net.iter_paths(&simple_point(A), &point_c)
+            .expect("should not throw iterating paths from A to C")
+            .take(2)
+            .collect();
+
+        assert_eq!(paths.len(), 2, "should only yield two paths");
+        assert!(paths.iter().all(|path| path.ends_with(&point_c)), "every path should end in C");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn for_each_path_should_stop_once_the_visitor_breaks() {
+        let diamond_net = diamond_net();
+        let mut all_paths = Vec::new();
+        let mut first_paths = Vec::new();
+
+        diamond_net.for_each_path(&simple_point(A), &simple_point(C), |path| {
+            all_paths.push(path.to_string());
+            ControlFlow::Continue(())
+        }).expect("should visit paths from A to C");
+        diamond_net.for_each_path(&simple_point(A), &simple_point(C), |path| {
+            first_paths.push(path.to_string());
+            if first_paths.len() == 2 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        }).expect("should visit paths from A to C");
+
+        assert_eq!(all_paths.len(), 4, "should visit the four feasible paths");
+        assert_eq!(first_paths, all_paths[..2].to_vec(), "should stop after the second path");
+    }
+
+    // Given this net of points, where B-C weighs 3 and A-D weighs 2:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn iter_paths_by_cost_should_start_with_the_cheapest_path() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = weighted_triangle_net(3.0, 2.0);
+
+        let paths: Vec<(Path<SimplePoint>, f64)> = net.iter_paths_by_cost(&point_a, &point_c)
+            .expect("should not throw iterating paths from A to C")
+            .collect();
+
+        assert_eq!(paths[0].0, net.shortest_path(&point_a, &point_c).unwrap(), "should yield the cheapest path first");
+        assert_eq!(paths.iter().map(|(path, cost)| format!("{} {}", path, cost)).collect::<Vec<String>>(), vec!["A-D-C 3", "A-B-C 4"], "should yield every path");
+        assert!(paths.windows(2).all(|pair| pair[0].1 <= pair[1].1), "costs should never decrease");
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn iter_paths_between_disconnected_points_should_yield_nothing() {
+        let net: Net<SimplePoint> = Net::new(vec![non_connected_node(simple_point(A)), non_connected_node(simple_point(B))]);
+
+        let mut paths = net.iter_paths(&simple_point(A), &simple_point(B))
+            .expect("should not throw iterating paths from A to B");
+
+        assert!(paths.next().is_none(), "there is no path from A to B");
+    }
+
+    // Given this net, where A lists B as connected but B has no node:
+    // A - (B) ; A - C
+    #[test]
+    fn find_paths_through_a_point_without_node_should_throw_instead_of_panicking() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        let net: Net<SimplePoint> = Net::new(vec![node_connected_to(point_a, vec![point_b, point_c]), node(point_c, point_a)]);
+
+        match net.find_paths(&point_a, &point_c) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "B"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn net_builder_should_build_a_net_from_its_edges() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        let net = NetBuilder::new()
+            .point(&point_a)
+            .point(&point_b)
+            .point(&point_c)
+            .point(&point_d)
+            .edge(&point_a, &point_b)
+            .edge(&point_a, &point_d)
+            .edge(&point_b, &point_c)
+            .edge(&point_c, &point_d)
+            .edge(&point_b, &point_d)
+            .build()
+            .expect("should build the net");
+
+        assert_eq!(net.nodes, diamond_net().nodes, "should create one node per point with both ends connected");
+        let paths = net.find_paths(&point_a, &point_c)
+            .expect("should find paths in the built net");
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should find the four feasible paths");
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn net_builder_should_build_directed_edges() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        let net = NetBuilder::new()
+            .edge(&point_a, &point_b)
+            .directed_edge(&point_b, &point_c)
+            .build()
+            .expect("should build the net");
+
+        assert!(net.find_paths(&point_a, &point_c).is_ok(), "should follow B -> C");
+        assert!(net.find_paths(&point_c, &point_a).is_err(), "should not go back through B -> C");
+    }
+
+    #[test]
+    fn net_builder_should_fail_if_different_points_share_an_id() {
+        #[derive(Clone, PartialEq, Debug)]
+        struct City {
+            name: char,
+            population: u32,
+        }
+
+        impl Point for City {
+            type Identifier = char;
+
+            fn id(&self) -> &char {
+                &self.name
+            }
+        }
+
+        let city_a = City { name: A, population: 10 };
+        let other_city_a = City { name: A, population: 20 };
+        let city_b = City { name: B, population: 30 };
+
+        let net = NetBuilder::new()
+            .edge(&city_a, &city_b)
+            .edge(&city_b, &other_city_a)
+            .build();
+
+        match net {
+            Err(NetErrors::ConflictingPoint(id)) => assert_eq!(id, "A"),
+            _ => panic!("ConflictingPoint error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_from_a_to_c_with_at_most_three_points_should_skip_the_detours() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let paths = net.find_paths_with_max_length(&point_a, &point_c, 3)
+            .expect("should find the direct paths from A to C");
+
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-D-C", "should only find the paths of three points");
+    }
+
+    #[test]
+    fn paths_with_a_length_long_enough_should_be_the_same_as_all_the_paths() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let all_paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+        let bounded_paths = net.find_paths_with_max_length(&point_a, &point_c, 4)
+            .expect("should find paths from A to C with at most four points");
+
+        assert_eq!(format_list_of_paths(bounded_paths), format_list_of_paths(all_paths), "a length of four should not prune any path");
+    }
+
+    #[test]
+    fn paths_with_a_length_too_short_to_reach_the_destination_should_not_be_found() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        match net.find_paths_with_max_length(&point_a, &point_c, 2) {
+            Err(NetErrors::NoPathFound) => (),
+            _ => panic!("NoPathFound error expected")
+        }
+    }
+
+    // Given this net of points, where B-C weighs 5 and A-D weighs 2:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn k_shortest_paths_from_a_to_c_should_be_sorted_by_cost() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let net = Net::new(vec![
+            weighted_node(point_a, vec![(point_b, 1.0), (point_d, 2.0)]),
+            weighted_node(point_b, vec![(point_a, 1.0), (point_c, 5.0), (point_d, 1.0)]),
+            weighted_node(point_c, vec![(point_b, 5.0), (point_d, 1.0)]),
+            weighted_node(point_d, vec![(point_a, 2.0), (point_c, 1.0), (point_b, 1.0)]),
+        ]);
+
+        let paths = net.k_shortest_paths(&point_a, &point_c, 3)
+            .expect("should find the three cheapest paths from A to C");
+
+        assert_eq!(format_paths_with_cost(&paths), vec!["A-D-C (3)", "A-B-D-C (3)", "A-B-C (6)"], "should sort by cost, then by hops");
+    }
+
+    #[test]
+    fn k_shortest_paths_should_return_every_path_when_there_are_less_than_k() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let paths = net.k_shortest_paths(&point_a, &point_c, 10)
+            .expect("should find every path from A to C");
+
+        assert_eq!(format_paths_with_cost(&paths), vec!["A-B-C (2)", "A-D-C (2)", "A-B-D-C (3)", "A-D-B-C (3)"], "should find the four feasible paths");
+    }
+
+    #[test]
+    fn k_shortest_paths_between_disconnected_points_should_not_be_found() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            node(point_b, point_a),
+            non_connected_node(point_c),
+        ]);
+
+        match net.k_shortest_paths(&point_a, &point_c, 2) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B   C
+    #[test]
+    fn zero_shortest_paths_should_be_found_without_searching() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            node(point_b, point_a),
+            non_connected_node(point_c),
+        ]);
+
+        let paths = net.k_shortest_paths(&point_a, &point_c, 0).expect("should not throw asking for no path");
+
+        assert!(paths.is_empty(), "should return no path even though C is unreachable");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_should_be_sorted_by_hops_and_then_by_ids() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+
+        let formatted_paths: Vec<String> = paths.iter().map(format_path_kebab).collect();
+        assert_eq!(formatted_paths, vec!["A-B-C", "A-D-C", "A-B-D-C", "A-D-B-C"], "shortest paths should come first");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_from_a_to_c_through_b_should_all_go_through_b() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let paths = net.find_paths_through(&point_a, &point_c, &[&point_b])
+            .expect("should find paths from A to C through B");
+
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-B-D-C + A-D-B-C", "should skip A-D-C");
+    }
+
+    #[test]
+    fn paths_through_b_and_d_should_visit_them_in_any_order() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let net = diamond_net();
+
+        let paths = net.find_paths_through(&point_a, &point_c, &[&point_d, &point_b])
+            .expect("should find paths from A to C through B and D");
+
+        assert_eq!(format_list_of_paths(paths), "A-B-D-C + A-D-B-C", "should find both orders");
+    }
+
+    #[test]
+    fn paths_through_a_point_not_in_the_net_should_throw() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let point_e = simple_point(E);
+        let net = diamond_net();
+
+        match net.find_paths_through(&point_a, &point_c, &[&point_e]) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "E"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_from_a_to_c_avoiding_b_should_go_through_d() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let paths = net.find_paths_avoiding(&point_a, &point_c, &[&point_b])
+            .expect("should find paths from A to C avoiding B");
+
+        assert_eq!(format_list_of_paths(paths), "A-D-C", "should only find A-D-C");
+    }
+
+    #[test]
+    fn paths_avoiding_every_route_should_not_be_found() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let net = diamond_net();
+
+        match net.find_paths_avoiding(&point_a, &point_c, &[&point_b, &point_d]) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn some_paths_to_an_unreachable_point_should_not_be_found() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net = Net::new(vec![non_connected_node(point_a), non_connected_node(point_b)]);
+
+        match net.find_some_paths(&point_a, &point_b, 2) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn paths_where_no_connection_is_allowed_should_not_be_found() {
+        let net = a_b_c_net();
+
+        match net.find_paths_where(&simple_point(A), &simple_point(C), |_, _| false) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of non connected points:
+    // A  B
+    #[test]
+    fn paths_with_budget_to_an_unreachable_point_should_not_be_found() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net = Net::new(vec![non_connected_node(point_a), non_connected_node(point_b)]);
+
+        match net.find_paths_with_budget(&point_a, &point_b, 10) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    #[test]
+    fn paths_avoiding_the_destination_should_throw() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        match net.find_paths_avoiding(&point_a, &point_c, &[&point_c]) {
+            Err(NetErrors::BlockedPoint(id)) => assert_eq!(id, "C"),
+            _ => panic!("BlockedPoint error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn removing_b_should_reroute_the_paths_from_a_to_c_through_d() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let mut net = diamond_net();
+
+        net.remove_point(&point_b).expect("should remove B");
+
+        assert!(net.validate().is_ok(), "no connection should lead to B anymore");
+        let paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+        assert_eq!(format_list_of_paths(paths), "A-D-C", "should go through D");
+    }
+
+    // Given this net of points, where A-B weighs 1 and A-D weighs 10:
+    // A - B - C - D
+    //  \         /
+    //   ---------
+    #[test]
+    fn shortest_hop_path_should_take_the_shortcut_whatever_its_weight() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let net = Net::new(vec![
+            weighted_node(point_a, vec![(point_b, 1.0), (point_d, 10.0)]),
+            weighted_node(point_b, vec![(point_a, 1.0), (point_c, 1.0)]),
+            weighted_node(point_c, vec![(point_b, 1.0), (point_d, 1.0)]),
+            weighted_node(point_d, vec![(point_c, 1.0), (point_a, 10.0)]),
+        ]);
+
+        let path = net.shortest_hop_path(&point_a, &point_d).expect("should find a path from A to D");
+
+        assert_eq!(format_path_kebab(&path), "A-D", "should go straight to D");
+        match Net::new(vec![non_connected_node(point_a), non_connected_node(point_b)]).shortest_hop_path(&point_a, &point_b) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of points, where B does not list A back:
+    // A - B - C
+    #[test]
+    fn symmetrize_should_add_the_missing_reverse_connection() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let mut net = Net::new(vec![
+            weighted_node(point_a, vec![(point_b, 3.0)]),
+            weighted_node(point_b, vec![(point_c, 1.0)]),
+            weighted_node(point_c, vec![(point_b, 1.0)]),
+        ]);
+
+        match net.assert_symmetric() {
+            Err(NetErrors::AsymmetricConnection(point_id, connected_point_id)) => assert_eq!((point_id.as_str(), connected_point_id.as_str()), ("A", "B")),
+            _ => panic!("AsymmetricConnection error expected")
+        }
+
+        net.symmetrize();
+
+        assert!(net.assert_symmetric().is_ok(), "should connect B back to A");
+        let path = net.shortest_path(&point_c, &point_a).expect("should find a path from C to A");
+        assert_eq!((format_path_kebab(&path), path.cost()), (String::from("C-B-A"), 4.0), "should weigh the reverse connection as the original one");
+    }
+
+    // Given these two nets of points, sharing C:
+    // A - B        C - D
+    //  \  |        |  /
+    //   \ |        | /
+    //     C        E
+    #[test]
+    fn merged_triangles_should_be_crossed_through_their_shared_point() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let point_e = simple_point(E);
+        let mut net = Net::from_edges(vec![(point_a, simple_point(B)), (simple_point(B), point_c), (point_c, point_a)]);
+        let other_net = Net::from_edges(vec![(point_c, simple_point(D)), (simple_point(D), point_e), (point_e, point_c), (point_c, point_a)]);
+
+        net.merge(other_net);
+
+        assert_eq!(net.nodes.len(), 5, "should have one node per point");
+        assert_eq!(net.degree_of(&point_c).expect("C should be in the net"), 4, "should not repeat the connection from C to A");
+        let paths = net.find_paths(&point_a, &point_e).expect("should find paths from A to E");
+        assert_eq!(format_list_of_paths(paths), "A-B-C-D-E + A-B-C-E + A-C-D-E + A-C-E", "should cross through C");
+    }
+
+    // Given this net of points, where every connection weighs 1:
+    // A - B - C - D - E - F
+    //                 |
+    //                 G
+    #[test]
+    fn contracted_chain_should_weigh_as_much_as_its_points() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E, F, G].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::from_edges(vec![
+            (points[0], points[1]), (points[1], points[2]), (points[2], points[3]),
+            (points[3], points[4]), (points[4], points[5]), (points[4], points[6]),
+        ]);
+
+        let contracted_net = net.contract_chains().expect("should contract the chain from A to E");
+        let contracted_path = contracted_net.shortest_path(&points[0], &points[5]).expect("should find a path from A to F");
+        let path = net.expand_path(&contracted_path).expect("should expand the contracted path");
+
+        assert_eq!(contracted_net.nodes.len(), 4, "should only keep A, E, F and G");
+        assert_eq!((format_path_kebab(&contracted_path), contracted_path.cost()), (String::from("A-E-F"), 5.0), "should jump from A to E");
+        assert_eq!((format_path_kebab(&path), path.cost()), (String::from("A-B-C-D-E-F"), 5.0), "should put back the chain");
+    }
+
+    // Given this net of points, where A-B-C weighs 2 and A-D-E-C weighs 3:
+    // F - A - B - C - G
+    //      \     /
+    //       D - E
+    #[test]
+    fn contraction_should_keep_the_cheapest_chain_between_two_points() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let mut net = Net::from_edges(vec![
+            (points[0], points[1]), (points[1], points[2]),
+            (points[0], points[3]), (points[3], points[4]), (points[4], points[2]),
+        ]);
+        net.add_node(non_connected_node(simple_point(F))).expect("should add F");
+        net.add_node(non_connected_node(simple_point(G))).expect("should add G");
+        net.connect(&points[0], &simple_point(F)).expect("should connect A and F");
+        net.connect(&points[2], &simple_point(G)).expect("should connect C and G");
+
+        let contracted_net = net.contract_chains().expect("should contract both chains");
+        let contracted_path = contracted_net.shortest_path(&points[0], &points[2]).expect("should find a path from A to C");
+
+        assert_eq!(contracted_net.degree_of(&points[0]).expect("A should be kept"), 2, "should join A to C once, and to F");
+        assert_eq!(format_path_kebab(&net.expand_path(&contracted_path).expect("should expand the path")), "A-B-C", "should go through the cheapest chain");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn walks_from_a_to_c_should_be_able_to_go_back_and_forth() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = a_b_c_net();
+
+        let walks = net.find_walks(&point_a, &point_c, 5).expect("should find walks from A to C");
+        let short_walks = net.find_walks(&point_a, &point_c, 4).expect("should find walks from A to C");
+
+        assert_eq!(walks.iter().map(|walk| walk.to_string()).collect::<Vec<String>>(), vec!["A-B-C", "A-B-A-B-C", "A-B-C-B-C"], "should revisit points");
+        assert_eq!(walks[1].cost(), 4.0, "should count A-B twice");
+        assert_eq!(short_walks.len(), 1, "only A-B-C is made of at most four points");
+    }
+
+    // Given this net of points:
+    // A - B - C   D
+    #[test]
+    fn walks_too_short_to_reach_the_destination_should_not_be_found() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let mut net = a_b_c_net();
+        net.add_node(non_connected_node(point_d)).expect("should add D");
+
+        match net.find_walks(&point_a, &point_c, 2) {
+            Err(NetErrors::NoPathFound) => (),
+            _ => panic!("NoPathFound error expected")
+        }
+        match net.find_walks(&point_a, &point_d, 5) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn walks_to_b_should_only_go_on_past_b_when_passing_through_is_allowed() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net = a_b_c_net();
+        let mut strict_options = SearchOptions::new();
+        strict_options.allow_passthrough_destination(false);
+
+        let strict_walks = net.find_walks_with_options(&point_a, &point_b, 4, &strict_options).expect("should find walks from A to B");
+        let passing_walks = net.find_walks_with_options(&point_a, &point_b, 4, &SearchOptions::new()).expect("should find walks from A to B");
+
+        assert_eq!(strict_walks.iter().map(|walk| walk.to_string()).collect::<Vec<String>>(), vec!["A-B"], "should stop at the first arrival at B");
+        assert_eq!(passing_walks.iter().map(|walk| walk.to_string()).collect::<Vec<String>>(), vec!["A-B", "A-B-A-B", "A-B-C-B"], "should go on past B and come back");
+    }
+
+    // Given this net of points:
+    // A - B - C - D
+    #[test]
+    fn connecting_a_and_d_should_add_a_shortcut() {
+        let point_a = simple_point(A);
+        let point_d = simple_point(D);
+        let mut net = a_b_c_net();
+        net.add_node(non_connected_node(point_d)).expect("should add D");
+        net.connect(&simple_point(C), &point_d).expect("should connect C and D");
+
+        net.connect(&point_a, &point_d).expect("should connect A and D");
+
+        let paths = net.find_paths(&point_a, &point_d).expect("should find paths from A to D");
+        assert_eq!(format_list_of_paths(paths), "A-B-C-D + A-D", "should find the shortcut");
+    }
+
+    #[test]
+    fn disconnecting_b_and_c_should_leave_no_path_from_a_to_c() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let mut net = a_b_c_net();
+
+        net.disconnect(&point_b, &point_c).expect("should disconnect B and C");
+
+        match net.find_paths(&point_a, &point_c) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    #[test]
+    fn cached_paths_should_be_forgotten_once_the_net_changes() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let mut net = a_b_c_net();
+
+        let paths = net.find_paths_cached(&point_a, &point_c).expect("should find paths from A to C");
+        let cached_paths = net.find_paths_cached(&point_a, &point_c).expect("should find cached paths from A to C");
+        assert_eq!(format_list_of_paths(paths), format_list_of_paths(cached_paths), "should return the same paths from the cache");
+
+        let version = net.version();
+        net.connect(&point_a, &point_c).expect("should connect A and C");
+        assert!(net.version() > version, "connecting points should change the version of the net");
+
+        let paths = net.find_paths_cached(&point_a, &point_c).expect("should find paths from A to C");
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-C", "should search the changed net again");
+    }
+
+    #[test]
+    fn adding_a_node_for_a_point_already_in_the_net_should_throw() {
+        let mut net = a_b_c_net();
+
+        match net.add_node(non_connected_node(simple_point(A))) {
+            Err(NetErrors::DuplicatePoint(id)) => assert_eq!(id, "A"),
+            _ => panic!("DuplicatePoint error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn net_from_edges_should_connect_both_ends_of_each_edge() {
+        let [point_a, point_b, point_c, point_d] = [A, B, C, D].map(simple_point);
+
+        let net = Net::from_edges(vec![
+            (point_a, point_b),
+            (point_b, point_c),
+            (point_c, point_d),
+            (point_d, point_a),
+            (point_b, point_d),
+            (point_d, point_b),
+        ]);
+
+        assert_eq!(net.nodes.len(), 4, "should create one node per point");
+        assert!(net.validate().is_ok(), "should build a consistent net");
+        let paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should find the four feasible paths");
+        assert_eq!(net.to_edge_list().len(), 5, "should collapse the repeated B-D edge");
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn c_should_be_reachable_from_a_but_not_the_other_way_around() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new().point(&point_b).connected_point(&point_a).directed_connected_point(&point_c).build().unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        assert!(net.is_reachable(&point_a, &point_c).expect("should check A to C"), "C should be reachable from A");
+        assert!(!net.is_reachable(&point_c, &point_a).expect("should check C to A"), "A should not be reachable from C");
+    }
+
+    #[test]
+    fn reachability_to_a_point_not_in_the_net_should_throw() {
+        let net = a_b_c_net();
+
+        match net.is_reachable(&simple_point(A), &simple_point(E)) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "E"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    #[test]
+    fn diamond_net_should_be_connected() {
+        let net = diamond_net();
+
+        assert!(net.is_connected(), "every point should be reachable");
+        assert!(net.is_strongly_connected(), "every point should be reachable in both directions");
+    }
+
+    // Given this net of points:
+    // A - B - C    D - E
+    #[test]
+    fn net_with_two_components_should_not_be_connected() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::from_edges(vec![(points[0], points[1]), (points[1], points[2]), (points[3], points[4])]);
+
+        assert!(!net.is_connected(), "D and E should not be reachable from A");
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn net_with_a_one_way_connection_should_be_connected_but_not_strongly() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new().point(&point_b).connected_point(&point_a).directed_connected_point(&point_c).build().unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        assert!(net.is_connected(), "ignoring directions every point should be reachable");
+        assert!(!net.is_strongly_connected(), "A and B should not be reachable from C");
+    }
+
+    #[test]
+    fn empty_net_should_be_connected() {
+        let net: Net<SimplePoint> = Net::new(Vec::new());
+
+        assert!(net.is_connected(), "an empty net should be connected");
+        assert!(net.is_strongly_connected(), "an empty net should be strongly connected");
+    }
+
+    // Given this net of points, where every connection is one-way:
+    // A -> B -> C -> A
+    //           |
+    //           v
+    //           D -> E -> D
+    #[test]
+    fn strongly_connected_components_should_group_the_cycles() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            directed_node(points[0], vec![points[1]]),
+            directed_node(points[1], vec![points[2]]),
+            directed_node(points[2], vec![points[0], points[3]]),
+            directed_node(points[3], vec![points[4]]),
+            directed_node(points[4], vec![points[3]]),
+        ]);
+
+        let components: Vec<Vec<char>> = net.strongly_connected_components().iter()
+            .map(|component| component.iter().map(|point| *point.id()).collect())
+            .collect();
+
+        assert_eq!(components, vec![vec![A, B, C], vec![D, E]], "should find the A-B-C and D-E cycles");
+    }
+
+    // Given this net of points, where every connection is one-way:
+    // A -> B -> C
+    #[test]
+    fn points_outside_any_cycle_should_be_their_own_component() {
+        let points: Vec<SimplePoint> = [A, B, C].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            directed_node(points[0], vec![points[1]]),
+            directed_node(points[1], vec![points[2]]),
+            non_connected_node(points[2]),
+        ]);
+
+        let components: Vec<Vec<char>> = net.strongly_connected_components().iter()
+            .map(|component| component.iter().map(|point| *point.id()).collect())
+            .collect();
+
+        assert_eq!(components, vec![vec![A], vec![B], vec![C]], "each point should be alone");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_search_should_find_the_same_paths_in_the_same_order() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = complete_net();
+
+        let paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+        let parallel_paths = net.par_find_paths(&point_a, &point_c).expect("should find paths from A to C in parallel");
+
+        assert_eq!(parallel_paths, paths, "should find the same paths in the same order");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn paths_from_a_to_c_should_only_follow_the_accepted_edges() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+        let calls = ::std::cell::Cell::new(0);
+
+        let paths = net.find_paths_where(&point_a, &point_c, |_, to| {
+            calls.set(calls.get() + 1);
+            *to.id() != D
+        }).expect("should find paths from A to C");
+
+        assert_eq!(format_list_of_paths(paths), "A-B-C", "should never go into D");
+        assert_eq!(calls.get(), 10, "should check each of the ten connections once");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn search_with_a_large_enough_budget_should_find_every_path() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        let paths = net.find_paths_with_budget(&point_a, &point_c, 4)
+            .expect("four expansions should be enough to find every path");
+
+        assert_eq!(format_list_of_paths(paths), "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should find the four feasible paths");
+    }
+
+    #[test]
+    fn search_exceeding_its_budget_should_throw_with_the_paths_found() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
+
+        match net.find_paths_with_budget(&point_a, &point_c, 2) {
+            Err(NetErrors::SearchBudgetExceeded(paths_found)) => assert_eq!(paths_found, vec![vec!["A", "B", "C"], vec!["A", "B", "D", "C"]]),
+            _ => panic!("SearchBudgetExceeded error expected")
+        }
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn points_by_degree_should_list_b_and_d_first() {
+        let net = diamond_net();
+
+        let degrees: Vec<(char, usize)> = net.points_by_degree().into_iter()
+            .map(|(point, degree)| (*point.id(), degree))
+            .collect();
+
+        assert_eq!(degrees, vec![(B, 3), (D, 3), (A, 2), (C, 2)], "B and D should have three connections");
+        assert_eq!(net.degree_of(&simple_point(A)).expect("A should be in the net"), 2, "A should have two connections");
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn one_way_connections_should_count_only_in_their_direction() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new().point(&point_b).connected_point(&point_a).directed_connected_point(&point_c).build().unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        assert_eq!(net.out_degree_of(&point_c).expect("C should be in the net"), 0, "no connection should leave C");
+        assert_eq!(net.in_degree_of(&point_c).expect("C should be in the net"), 1, "B -> C should lead to C");
+        assert_eq!(net.in_degree_of(&point_b).expect("B should be in the net"), 1, "only A should lead to B");
+    }
+
+    // Given this net of points:
+    // A - B - C
+    //  \  |  /
+    //   \ | /
+    //     D
+    #[test]
+    fn neighbors_of_b_should_be_listed_in_declaration_order() {
+        let net = diamond_net();
+
+        let neighbors = net.neighbors(&simple_point(B)).expect("B should be in the net");
+
+        assert_eq!(neighbors.iter().map(|point| *point.id()).collect::<Vec<char>>(), vec![A, C, D], "should keep the order of the connections of B");
+        match net.neighbors(&simple_point(E)) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "E"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    #[test]
+    fn degree_of_a_point_not_in_the_net_should_throw() {
+        match a_b_c_net().degree_of(&simple_point(E)) {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "E"),
+            _ => panic!("PointNotFound error expected")
+        }
+    }
+
+    // Given this net of points, where B -> C is a one-way connection:
+    // A - B -> C
+    #[test]
+    fn edges_should_yield_undirected_edges_once_and_one_way_connections_in_their_direction() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new().point(&point_b).connected_point(&point_a).directed_connected_point(&point_c).build().unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        let edges: Vec<(char, char)> = net.edges()
+            .map(|(from, to)| (*from.id(), *to.id()))
+            .collect();
+
+        assert_eq!(edges, vec![(A, B), (B, C)], "should skip B-A and keep B -> C");
+    }
+
+    #[test]
+    fn total_weight_should_add_up_every_edge_once() {
+        let net = weighted_triangle_net(3.0, 2.0);
+
+        assert_eq!(total_weight(&net), 7.0, "should add A-B, A-D, B-C and C-D");
+    }
+
+    #[test]
+    fn paths_should_outlive_the_points_used_to_find_them() {
+        let net = diamond_net();
+        let paths = {
+            let point_a = simple_point(A);
+            let point_c = simple_point(C);
+            net.find_paths(&point_a, &point_c).expect("should find paths from A to C")
+        };
+
+        let formatted_paths = ::std::thread::spawn(move || format_list_of_paths(paths))
+            .join()
+            .expect("should format the paths in another thread");
+
+        assert_eq!(formatted_paths, "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "paths should own their points");
+    }
+
+    // Given this net of points, where B-C weighs 3 and A-D weighs 2:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn distance_matrix_should_hold_the_lowest_weight_between_every_pair() {
+        let net = weighted_triangle_net(3.0, 2.0);
+
+        let distances = net.distance_matrix();
+
+        assert_eq!(distances.len(), 16, "every point should reach every other");
+        assert_eq!(distances[&(A, A)], 0.0, "A should be at zero from itself");
+        assert_eq!(distances[&(A, C)], 3.0, "A-D-C should weigh 3");
+        assert_eq!(distances[&(B, D)], 3.0, "B-A-D should weigh 3");
+        assert_eq!(distances[&(D, B)], 3.0, "D-A-B should weigh 3");
+    }
+
+    // Given this net of points, where A-D weighs 5:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn diameter_should_be_the_greatest_eccentricity() {
+        let net = weighted_triangle_net(1.0, 5.0);
+
+        let eccentricity_of_a = net.eccentricity(&simple_point(A)).expect("should compute the eccentricity of A");
+        let eccentricity_of_b = net.eccentricity(&simple_point(B)).expect("should compute the eccentricity of B");
+
+        assert_eq!(eccentricity_of_a, 3.0, "A-B-C-D should weigh 3");
+        assert_eq!(eccentricity_of_b, 2.0, "B should be at most 2 away from any point");
+        assert_eq!(net.diameter(), Some(3.0), "A and D should be the farthest apart");
+    }
+
+    // Given this net of points, where every connection is one-way, A -> B weighs 4, A -> C 1,
+    // C -> B -2 and B -> A 1:
+    // A <-> B
+    //  \    ^
+    //   v  /
+    //    C
+    #[test]
+    fn eccentricities_should_agree_with_the_diameter_despite_a_negative_weight() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&point_a)
+                .directed_connected_point_with_weight(&point_b, 4.0)
+                .directed_connected_point_with_weight(&point_c, 1.0)
+                .build().unwrap(),
+            NodeBuilder::new().point(&point_b).directed_connected_point_with_weight(&point_a, 1.0).build().unwrap(),
+            NodeBuilder::new().point(&point_c).directed_connected_point_with_weight(&point_b, -2.0).build().unwrap(),
+        ]);
+
+        let eccentricities: Vec<f64> = [point_a, point_b, point_c].iter()
+            .map(|point| net.eccentricity(point).expect("should compute the eccentricity"))
+            .collect();
+
+        assert_eq!(eccentricities, vec![1.0, 2.0, 0.0], "should go from C to B through the negative connection");
+        assert_eq!(net.diameter(), Some(2.0), "should be the greatest eccentricity");
+    }
+
+    // Given this net of points:
+    // A - B   C
+    #[test]
+    fn disconnected_net_should_have_no_diameter_and_infinite_eccentricities() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net = Net::new(vec![node(point_a, point_b), node(point_b, point_a), non_connected_node(simple_point(C))]);
+
+        assert_eq!(net.diameter(), None, "C cannot be reached from A or B");
+        assert_eq!(net.eccentricity(&point_a).expect("should compute the eccentricity of A"), f64::INFINITY, "C cannot be reached from A");
+    }
+
+    // Given this net of points, where B-C weighs 3 and A-D weighs 2:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn inverting_weights_should_make_the_heaviest_path_the_cheapest() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = weighted_triangle_net(3.0, 2.0);
+
+        let inverted_net = net.with_inverted_weights(3.0);
+
+        let path = net.shortest_path(&point_a, &point_c).expect("should find the cheapest path from A to C");
+        let inverted_path = inverted_net.shortest_path(&point_a, &point_c).expect("should find the cheapest inverted path from A to C");
+        assert_eq!(path.to_string(), "A-D-C", "A-D-C should weigh 3 against 4 for A-B-C");
+        assert_eq!(inverted_path.to_string(), "A-B-C", "A-B-C should weigh 2 against 3 for A-D-C once inverted");
+        assert_eq!(inverted_path.cost(), 2.0, "A-B should weigh 2 and B-C 0");
+    }
+
+    // Given this net of points, where A -> B weighs 2 and B -> C weighs 1:
+    // A -> B -> C
+    #[test]
+    fn adjacency_matrix_should_hold_the_weight_of_every_connection() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&point_a).directed_connected_point_with_weight(&point_b, 2.0).build().unwrap(),
+            NodeBuilder::new().point(&point_b).directed_connected_point(&point_c).build().unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        let (ids, matrix) = net.adjacency_matrix();
+
+        assert_eq!(ids, vec![A, B, C], "should keep the order of the nodes");
+        assert_eq!(matrix, vec![vec![0.0, 2.0, 0.0], vec![0.0, 0.0, 1.0], vec![0.0, 0.0, 0.0]], "should leave 0 where there is no connection");
+    }
+
+    // Given this net of points, where A -> B weighs 2 and B -> C weighs -1:
+    // A -> B -> C
+    #[test]
+    fn distance_matrix_should_support_negative_weights_and_skip_unreachable_pairs() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&point_a).directed_connected_point_with_weight(&point_b, 2.0).build().unwrap(),
+            NodeBuilder::new().point(&point_b).directed_connected_point_with_weight(&point_c, -1.0).build().unwrap(),
+            non_connected_node(point_c),
+        ]);
+
+        let distances = net.distance_matrix();
+
+        assert_eq!(distances[&(A, C)], 1.0, "A -> B -> C should weigh 1");
+        assert!(!distances.contains_key(&(C, A)), "A should not be reachable from C");
+    }
+
+    // Given this net of points, where B-C weighs 3 and A-D weighs 2:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn bellman_ford_should_find_the_same_path_as_dijkstra_without_negative_weights() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = weighted_triangle_net(3.0, 2.0);
+
+        let dijkstra_path = net.shortest_path(&point_a, &point_c).expect("should find the shortest path with Dijkstra");
+        let bellman_ford_path = net.shortest_path_bellman_ford(&point_a, &point_c).expect("should find the shortest path with Bellman-Ford");
+
+        assert_eq!(bellman_ford_path, dijkstra_path, "both should go through D");
+        assert_eq!(bellman_ford_path.cost(), 3.0, "A-D-C should weigh 3");
+    }
+
+    // Given this net of points, where every connection is one-way:
+    // A -(1)-> B -(4)-> D
+    // A -(3)-> C -(-3)-> B
+    #[test]
+    fn bellman_ford_should_take_the_rebate() {
+        let points: Vec<SimplePoint> = [A, B, C, D].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&points[0]).directed_connected_point_with_weight(&points[1], 1.0).directed_connected_point_with_weight(&points[2], 3.0).build().unwrap(),
+            NodeBuilder::new().point(&points[1]).directed_connected_point_with_weight(&points[3], 4.0).build().unwrap(),
+            NodeBuilder::new().point(&points[2]).directed_connected_point_with_weight(&points[1], -3.0).build().unwrap(),
+            non_connected_node(points[3]),
+        ]);
+
+        let path = net.shortest_path_bellman_ford(&points[0], &points[3]).expect("should find the shortest path from A to D");
+
+        assert_eq!(format_path_kebab(&path), "A-C-B-D", "should go through the rebate from C to B");
+        assert_eq!(path.cost(), 4.0, "A-C-B-D should weigh 4");
     }
 
-    fn find_paths_not_crossing_previous_path(&self, origin: &Node<T>, destination: &T, previous_path: &Path<T>) -> Option<Vec<Path<T>>> {
-        match origin.connected_points_not_in_path(previous_path) {
-            None => None,
-            Some(followable_points) => {
-                let paths = self.all_paths_to_destination_following_path_and_continuing_with_points(&destination, previous_path, followable_points);
+    // Given this net of points, where every connection is one-way:
+    // A -(1)-> B -(1)-> C -(-3)-> B
+    #[test]
+    fn bellman_ford_should_throw_on_a_reachable_negative_cycle() {
+        let points: Vec<SimplePoint> = [A, B, C].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&points[0]).directed_connected_point_with_weight(&points[1], 1.0).build().unwrap(),
+            NodeBuilder::new().point(&points[1]).directed_connected_point_with_weight(&points[2], 1.0).build().unwrap(),
+            NodeBuilder::new().point(&points[2]).directed_connected_point_with_weight(&points[1], -3.0).build().unwrap(),
+        ]);
 
-                if paths.is_empty() {
-                    None
-                } else {
-                    Some(paths)
-                }
-            }
+        match net.shortest_path_bellman_ford(&points[0], &points[2]) {
+            Err(NetErrors::NegativeCycle) => (),
+            _ => panic!("NegativeCycle error expected")
         }
     }
 
-    fn all_paths_to_destination_following_path_and_continuing_with_points(&self, destination: &&T, previous_path: &Path<T>, followable_points: Vec<&T>) -> Vec<Path<T>> {
-        followable_points
-            .into_iter()
-            .map(|point| self.all_paths_to_destination_following_path_and_continuing_with_point(&destination, previous_path, point))
-            .fold(Vec::new(), |paths: Vec<Path<T>>, path_search: Option<Vec<Path<T>>>|
-                match path_search {
-                    Some(paths_found) => paths.into_iter().chain(paths_found.into_iter()).collect(),
-                    None => paths
-                },
-            )
-    }
+    // Given this net of points, where A-B weighs 1, B-C 2, A-D 3, A-C 4, B-D 5 and C-D 6:
+    // A - B
+    // | X |
+    // D - C
+    #[test]
+    fn minimum_spanning_tree_of_a_complete_net_should_keep_the_three_cheapest_edges() {
+        let points: Vec<SimplePoint> = [A, B, C, D].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            weighted_node(points[0], vec![(points[1], 1.0), (points[2], 4.0), (points[3], 3.0)]),
+            weighted_node(points[1], vec![(points[0], 1.0), (points[2], 2.0), (points[3], 5.0)]),
+            weighted_node(points[2], vec![(points[0], 4.0), (points[1], 2.0), (points[3], 6.0)]),
+            weighted_node(points[3], vec![(points[0], 3.0), (points[1], 5.0), (points[2], 6.0)]),
+        ]);
 
-    fn all_paths_to_destination_following_path_and_continuing_with_point(&self, destination: &T, following_path: &Path<T>, next_point: &T) -> Option<Vec<Path<T>>> {
-        let origin_node = self.find_node_or_panic(next_point);
-        let trying_path = following_path.with_point_at_the_end(next_point);
-        if trying_path.ends_with(destination) {
-            Some(vec![trying_path])
-        } else {
-            self.find_paths_not_crossing_previous_path(origin_node, &destination, &trying_path)
-        }
+        let tree = net.minimum_spanning_tree().expect("should build the minimum spanning tree");
+
+        let mut edges = tree.to_edge_list();
+        edges.sort();
+        assert_eq!(edges, vec![(A, B), (A, D), (B, C)], "should keep A-B, B-C and A-D");
+        assert_eq!(total_weight(&tree), 6.0, "should weigh 1 + 2 + 3");
     }
 
-    fn find_node_or_throws(&self, point: &T) -> Result<&Node<T>, NetErrors> {
-        let node_point = self.nodes.iter()
-            .find(|node| node.point_is(point));
+    // Given this net of points:
+    // A - B - C    D - E
+    #[test]
+    fn minimum_spanning_tree_of_a_disconnected_net_should_be_a_forest() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::from_edges(vec![(points[0], points[1]), (points[1], points[2]), (points[3], points[4])]);
+
+        let forest = net.minimum_spanning_tree().expect("should build the minimum spanning forest");
 
-        match node_point {
-            Some(ref node) => Ok(node),
-            None => Err(NetErrors::PointNotFound(point.id().to_string()))
-        }
+        assert_eq!(forest.to_edge_list(), vec![(A, B), (B, C), (D, E)], "should keep every edge of both trees");
     }
 
-    fn find_node_or_panic(&self, point: &T) -> &Node<T> {
-        match self.find_node_or_throws(point) {
-            Ok(ref node) => node,
-            Err(err) => panic!(err)
-        }
+    // Given this net of points, where B-C weighs 3 and A-D weighs 2:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn bidirectional_search_should_find_a_path_as_cheap_as_dijkstra() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = weighted_triangle_net(3.0, 2.0);
+
+        let dijkstra_path = net.shortest_path(&point_a, &point_c).expect("should find the shortest path with Dijkstra");
+        let bidirectional_path = net.shortest_path_bidirectional(&point_a, &point_c).expect("should find the shortest path from both ends");
+
+        assert_eq!(bidirectional_path.cost(), dijkstra_path.cost(), "both should weigh the same");
+        assert_eq!(format_path_kebab(&bidirectional_path), "A-D-C", "should go through D");
     }
-}
 
-quick_error! {
-    #[derive(Debug)]
-    pub enum NetErrors {
-        PointNotFound(point_id: String) {
-            description("Point does not exists in the net")
-            display(r#"The point with id "{}" could not be found"#, point_id)
-        }
-        NoPathFound {
-            description("No path found between points")
-            display(r#"No path found between points"#)
-        }
-        PathCannotBeBuilt(path_error: String) {
-            description("Path cannot be built")
-            display(r#"Path cannot be built: {}"#, path_error)
+    // Given this net of points, where every connection is one-way:
+    // A -> B -> C -> D -> E
+    #[test]
+    fn bidirectional_search_should_follow_one_way_connections() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::new(vec![
+            directed_node(points[0], vec![points[1]]),
+            directed_node(points[1], vec![points[2]]),
+            directed_node(points[2], vec![points[3]]),
+            directed_node(points[3], vec![points[4]]),
+            non_connected_node(points[4]),
+        ]);
+
+        let path = net.shortest_path_bidirectional(&points[0], &points[4]).expect("should find the path from A to E");
+
+        assert_eq!(format_path_kebab(&path), "A-B-C-D-E", "should go along the chain");
+        match net.shortest_path_bidirectional(&points[4], &points[0]) {
+            Err(NetErrors::DestinationUnreachable(_, _)) => (),
+            _ => panic!("DestinationUnreachable error expected")
         }
     }
-}
-
 
-#[cfg(test)]
-mod test {
-    use net::*;
-    use node::Point;
-    use node::Node;
-    use path::Path;
-    use node::NodeBuilder;
+    // Given this net of points:
+    // A - B - C - D - E
+    #[test]
+    fn bidirectional_search_should_expand_fewer_points_than_dijkstra() {
+        let points: Vec<SimplePoint> = [A, B, C, D, E].iter().map(|&name| simple_point(name)).collect();
+        let net = Net::from_edges(points.windows(2).map(|pair| (pair[0], pair[1])));
 
-    const A: char = 'A';
-    const B: char = 'B';
-    const C: char = 'C';
-    const D: char = 'D';
+        let dijkstra_expansions = net.shortest_path_expansions(&points[1], &points[3]).expect("should find the path from B to D with Dijkstra");
+        let bidirectional_expansions = net.shortest_path_bidirectional_expansions(&points[1], &points[3]).expect("should find the path from B to D from both ends");
 
-    #[derive(Copy, Clone, PartialEq, Debug)]
-    struct SimplePoint {
-        name: char
+        assert_eq!(dijkstra_expansions, 3, "Dijkstra should expand B, A and C");
+        assert_eq!(bidirectional_expansions, 2, "the searches should meet at C after expanding B and D");
     }
 
-    impl Point for SimplePoint {
-        type Identifier = char;
+    // Given this net of points:
+    // A - B - C
+    //  \     /
+    //   \   /
+    //     D
+    #[test]
+    fn paths_sampled_with_the_same_seed_should_be_the_same() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = triangle_net();
+
+        let path = net.sample_path(&point_a, &point_c, 7).expect("should sample a path from A to C");
+        let same_path = net.sample_path(&point_a, &point_c, 7).expect("should sample a path from A to C");
+
+        assert_eq!(path, same_path, "should draw the same path from the same seed");
+        assert!(net.find_paths(&point_a, &point_c).expect("should find paths from A to C").contains(&path), "should be a path of the net");
+    }
 
-        fn id(&self) -> char {
-            self.name
+    // Given this net of points, where H is connected to the 26 lowercase points and each of
+    // them to D:
+    // A - H - a - D
+    //      \ ... /
+    //       - z -
+    #[test]
+    fn sampling_with_a_max_fanout_should_draw_few_connections_per_point() {
+        let point_a = simple_point(A);
+        let point_d = simple_point(D);
+        let hub = simple_point('H');
+        let mut edges = vec![(point_a, hub)];
+        for name in 'a'..='z' {
+            edges.push((hub, simple_point(name)));
+            edges.push((simple_point(name), point_d));
         }
+        let net = Net::from_edges(edges);
+
+        let (path, drawn) = net.sample_route(&point_a, &point_d, 42, 3).expect("should sample a path from A to D");
+        let (_, drawn_without_fanout) = net.sample_route(&point_a, &point_d, 42, usize::MAX).expect("should sample a path from A to D");
+
+        assert_eq!(path.len(), 4, "should go from A to D through H and a lowercase point");
+        assert!(drawn <= 3 * 3, "should draw at most three connections at each of A, H and the lowercase point");
+        assert!(drawn_without_fanout >= 27, "should draw every connection of H");
     }
 
-    // Given this net:
-    // A - B
+    // Given this net of points, where A and B are joined twice:
+    // A = B - C
     #[test]
-    fn find_paths_from_a_point_not_in_the_net_should_throw_an_exception() {
+    fn duplicate_a_b_links_should_be_reported_as_parallel_edges() {
         let point_a = simple_point(A);
         let point_b = simple_point(B);
         let point_c = simple_point(C);
+        let net = Net::new(vec![
+            parallel_weighted_node(point_a, vec![(point_b, 3.0), (point_b, 5.0)]),
+            parallel_weighted_node(point_b, vec![(point_a, 3.0), (point_a, 5.0), (point_c, 1.0)]),
+            node(point_c, point_b),
+        ]);
 
-        let node_a = node(point_a, point_b);
-        let node_b = node(point_b, point_a);
+        assert!(net.has_parallel_edges(), "should be a multigraph");
+        assert_eq!(net.parallel_edge_pairs(), vec![(A, B)], "should report A-B once");
+        assert!(!a_b_c_net().has_parallel_edges(), "should be a simple net");
+    }
 
-        let a_b_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b]
-        };
+    // Given this net of points, where A and B are joined twice, weighing 3 and 5:
+    // A = B - C
+    #[test]
+    fn merging_parallel_edges_with_min_should_keep_the_cheapest_a_b_link() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let mut net = Net::new(vec![
+            parallel_weighted_node(point_a, vec![(point_b, 3.0), (point_b, 5.0)]),
+            parallel_weighted_node(point_b, vec![(point_a, 3.0), (point_a, 5.0), (point_c, 1.0)]),
+            node(point_c, point_b),
+        ]);
+        let version = net.version();
 
-        let paths = a_b_net.find_paths(&point_c, &point_a);
+        net.merge_parallel_edges(f64::min);
 
-        assert!(paths.is_err(), "Should not be able to find the path from a point that does not exists in the net");
+        assert!(!net.has_parallel_edges(), "should be a simple net");
+        assert_eq!(net.degree_of(&point_a).expect("A should be in the net"), 1, "should join A to B once");
+        assert_eq!(net.adjacency_matrix().1[1][0], 3.0, "should keep the weight of the cheapest link");
+        let path = net.shortest_path(&point_a, &point_c).expect("should still find points after merging");
+        assert_eq!(path.cost(), 4.0, "should go through the merged link");
+        assert!(net.version() > version, "should count as a change of the net");
     }
 
-    // Given this net:
-    // A - B
+    // Given this net of points, where every connection is one-way:
+    // D -> B -> A
+    // |         ^
+    // v         |
+    // C --------
     #[test]
-    fn find_paths_to_a_point_not_in_the_net_should_throw_an_exception() {
+    fn topological_sort_should_put_every_point_before_the_points_it_connects_to() {
         let point_a = simple_point(A);
         let point_b = simple_point(B);
         let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let net = Net::new(vec![
+            non_connected_node(point_a),
+            directed_node(point_b, vec![point_a]),
+            directed_node(point_c, vec![point_a]),
+            directed_node(point_d, vec![point_b, point_c]),
+        ]);
 
-        let node_a = node(point_a, point_b);
-        let node_b = node(point_b, point_a);
-
-        let a_b_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b]
-        };
-
-        let paths = a_b_net.find_paths(&point_a, &point_c);
+        let sorted = net.topological_sort().expect("should sort an acyclic net");
 
-        assert!(paths.is_err(), "Should not be able to find the path to a point that does not exists in the net");
+        assert_eq!(sorted.iter().map(|point| *point.id()).collect::<Vec<char>>(), vec![D, B, C, A], "should keep insertion order between B and C");
     }
 
-    // Given this net:
-    // A - B
+    // Given this net of points, where every connection is one-way:
+    // A -> B -> C
+    // ^         |
+    //  ---------
     #[test]
-    fn in_a_b_net_find_path_should_find_a_path_from_a_to_b() {
+    fn topological_sort_of_a_net_with_a_cycle_should_throw() {
         let point_a = simple_point(A);
         let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = Net::new(vec![
+            directed_node(point_a, vec![point_b]),
+            directed_node(point_b, vec![point_c]),
+            directed_node(point_c, vec![point_a]),
+        ]);
 
-        let node_a = node(point_a, point_b);
-        let node_b = node(point_b, point_a);
+        match net.topological_sort() {
+            Err(NetErrors::CycleDetected) => (),
+            _ => panic!("CycleDetected error expected")
+        }
+        match net.critical_path(&point_a, &point_c) {
+            Err(NetErrors::CycleDetected) => (),
+            _ => panic!("CycleDetected error expected")
+        }
+    }
 
-        let a_b_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b]
-        };
+    // Given this net of points, where every connection is one-way and A -> B weighs 3,
+    // A -> C weighs 2, B -> C weighs 2, B -> D weighs 1 and C -> D weighs 4:
+    // A -> B -> D
+    //  \   |   ^
+    //   \  v  /
+    //     C
+    #[test]
+    fn critical_path_should_be_the_heaviest_path() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&point_a)
+                .directed_connected_point_with_weight(&point_b, 3.0)
+                .directed_connected_point_with_weight(&point_c, 2.0)
+                .build().unwrap(),
+            NodeBuilder::new().point(&point_b)
+                .directed_connected_point_with_weight(&point_c, 2.0)
+                .directed_connected_point_with_weight(&point_d, 1.0)
+                .build().unwrap(),
+            NodeBuilder::new().point(&point_c).directed_connected_point_with_weight(&point_d, 4.0).build().unwrap(),
+            non_connected_node(point_d),
+        ]);
 
-        let paths = a_b_net.find_paths(&point_a, &point_b)
-            .expect("Unexpected error while finding path");
+        let (path, length) = net.critical_path(&point_a, &point_d).expect("should find the critical path from A to D");
 
-        assert_eq!(format_list_of_paths(paths), "A-B", "Found path should be A-B");
+        assert_eq!(path.to_string(), "A-B-C-D", "should go through every point");
+        assert_eq!(length, 9.0, "A -> B -> C -> D should weigh 9");
     }
 
-    // Given this net of non connected points:
-    // A  B
     #[test]
-    fn in_there_is_no_path_from_a_to_b_find_path_should_throw() {
+    fn some_paths_should_be_the_first_paths_found() {
         let point_a = simple_point(A);
-        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let net = diamond_net();
 
-        let node_a = non_connected_node(point_a);
-        let node_b = non_connected_node(point_b);
+        let all_paths: Vec<Path<SimplePoint>> = net.iter_paths(&point_a, &point_c).expect("should iterate paths from A to C").collect();
+        let some_paths = net.find_some_paths(&point_a, &point_c, 2).expect("should find two paths from A to C");
 
-        let a_b_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b]
-        };
+        assert_eq!(some_paths.len(), 2, "should stop after two paths");
+        assert_eq!(format_path_kebab(&some_paths[0]), format_path_kebab(&all_paths[0]), "first path should be the same");
+        assert_eq!(format_path_kebab(&some_paths[1]), format_path_kebab(&all_paths[1]), "second path should be the same");
+    }
 
-        let paths = a_b_net.find_paths(&point_a, &point_b);
+    #[test]
+    fn some_paths_without_limit_should_be_all_the_paths() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let net = diamond_net();
 
-        match paths {
-            Ok(_) => panic!("should throw an error"),
-            Err(ref err) => {
-                match err {
-                    NetErrors::NoPathFound => assert!(true),
-                    _ => panic!("NoPathFound exception expected")
-                }
-            }
-        }
+        let all_paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+        let some_paths = net.find_some_paths(&point_a, &point_c, usize::MAX).expect("should find every path from A to C");
+
+        assert_eq!(some_paths, all_paths, "should find every path in the same order");
     }
 
-    // Given this net of points:
-    // A - B - C
     #[test]
-    fn in_an_a_b_c_net_should_find_a_path_from_a_to_c() {
+    fn some_paths_should_be_sorted_whether_the_limit_is_reached_or_not() {
         let point_a = simple_point(A);
-        let point_b = simple_point(B);
         let point_c = simple_point(C);
+        let net = diamond_net();
 
-        let node_a = node(point_a, point_b);
-        let node_b = node_connected_to(point_b, vec![point_a, point_c]);
-        let node_c = node(point_c, point_b);
+        let limited_paths = net.find_some_paths(&point_a, &point_c, 4).expect("should find the four paths from A to C");
+        let paths = net.find_some_paths(&point_a, &point_c, 5).expect("should find the four paths from A to C");
 
-        let a_b_c_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b, node_c]
-        };
+        assert_eq!(limited_paths, paths, "should not depend on the limit being reached");
+        assert_eq!(limited_paths.iter().map(format_path_kebab).collect::<Vec<String>>(), vec!["A-B-C", "A-D-C", "A-B-D-C", "A-D-B-C"], "should be sorted by hops and then by ids");
+    }
 
-        let paths = a_b_c_net.find_paths(&point_a, &point_c)
-            .expect(&format!("should not throw exception finding path a to c in net {:?}", a_b_c_net).into_boxed_str());
+    #[test]
+    fn validate_should_accept_a_well_formed_net() {
+        assert!(diamond_net().validate().is_ok(), "diamond net should be valid");
+    }
 
-        assert_eq!("A-B-C", format_list_of_paths(paths), "found path should be A-B-C");
+    #[test]
+    fn validate_should_report_points_with_several_nodes() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let net = Net::new(vec![
+            node_connected_to(point_a, vec![point_b]),
+            node_connected_to(point_b, vec![point_a]),
+            node_connected_to(point_a, vec![point_b]),
+        ]);
+
+        match net.validate() {
+            Err(NetErrors::DuplicatePoint(id)) => assert_eq!(id, "A"),
+            _ => panic!("DuplicatePoint error expected")
+        }
     }
 
-    // Given this net of points:
-    // A - B - C
-    //  \     /
-    //   \   /
-    //     D
     #[test]
-    fn in_triangle_net_should_find_two_paths_from_a_to_c() {
+    fn validate_should_report_connections_to_points_out_of_the_net() {
         let point_a = simple_point(A);
         let point_b = simple_point(B);
         let point_c = simple_point(C);
-        let point_d = simple_point(D);
-
-        let node_a = node_connected_to(point_a, vec![point_b, point_d]);
-        let node_b = node_connected_to(point_b, vec![point_a, point_c]);
-        let node_c = node_connected_to(point_c, vec![point_b, point_d]);
-        let node_d = node_connected_to(point_d, vec![point_a, point_c]);
+        let net = Net::new(vec![
+            node_connected_to(point_a, vec![point_b]),
+            node_connected_to(point_b, vec![point_a, point_c]),
+        ]);
 
-        let triangle_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b, node_c, node_d]
-        };
+        match net.validate() {
+            Err(NetErrors::DanglingConnection(id, connected_id)) => {
+                assert_eq!(id, "B");
+                assert_eq!(connected_id, "C");
+            },
+            _ => panic!("DanglingConnection error expected")
+        }
+    }
 
-        let paths = triangle_net.find_paths(&point_a, &point_c)
-            .expect(&format!("should not throw exception finding path a to c in net {:?}", triangle_net).into_boxed_str());
+    // Given this net of points, where every point is connected to every other:
+    // A - B
+    // | X |
+    // D - C
+    #[test]
+    fn iterative_and_recursive_searches_should_find_the_same_paths() {
+        let point_a = simple_point(A);
+        let point_c = simple_point(C);
+        let complete_net = complete_net();
 
-        let formatted_paths = format_list_of_paths(paths);
+        let iterative_paths = complete_net.find_paths_with_options(&point_a, &point_c, SearchOptions::new().iterative(true))
+            .expect("should find paths from A to C iteratively");
+        let recursive_paths = complete_net.find_paths_with_options(&point_a, &point_c, SearchOptions::new().iterative(false))
+            .expect("should find paths from A to C recursively");
 
-        assert_eq!(formatted_paths, "A-B-C + A-D-C", "should find A-B-C and A-D-C paths");
+        assert_eq!(iterative_paths, recursive_paths, "both searches should find the same paths in the same order");
+        assert_eq!(iterative_paths.len(), 5, "A-C, A-B-C, A-D-C, A-B-D-C and A-D-B-C");
     }
 
-    // Given this net of points:
+    // Given this net of points, where B only knows the ids of A and C:
     // A - B - C
-    //  \  |  /
-    //   \ | /
-    //     D
     #[test]
-    fn should_find_all_feasible_paths_from_a_to_c() {
+    fn connections_by_id_should_be_resolved_when_the_net_is_built() {
         let point_a = simple_point(A);
         let point_b = simple_point(B);
         let point_c = simple_point(C);
-        let point_d = simple_point(D);
+        let net = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new().point(&point_b).connected_id(A).connected_id(C).build().unwrap(),
+            node(point_c, point_b),
+        ]);
 
-        let node_a = node_connected_to(point_a, vec![point_b, point_d]);
-        let node_b = node_connected_to(point_b, vec![point_a, point_c, point_d]);
-        let node_c = node_connected_to(point_c, vec![point_b, point_d]);
-        let node_d = node_connected_to(point_d, vec![point_a, point_c, point_b]);
+        assert!(net.validate().is_ok(), "every id should match a node");
+        let paths = net.find_paths(&point_a, &point_c).expect("should find paths from A to C");
+        assert_eq!(format_list_of_paths(paths), "A-B-C", "B should be connected to C");
+    }
 
-        let triangle_net: Net<SimplePoint> = Net {
-            nodes: vec![node_a, node_b, node_c, node_d]
-        };
+    #[test]
+    fn validate_should_report_connections_by_id_to_points_out_of_the_net() {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let mut net = Net::new(vec![
+            node(point_a, point_b),
+            NodeBuilder::new().point(&point_b).connected_id(A).connected_id(C).build().unwrap(),
+        ]);
 
-        let paths = triangle_net.find_paths(&point_a, &point_c)
-            .expect(&format!("should not throw exception finding path a to c in net {:?}", triangle_net).into_boxed_str());
+        match net.validate() {
+            Err(NetErrors::PointNotFound(id)) => assert_eq!(id, "C"),
+            _ => panic!("PointNotFound error expected")
+        }
 
-        let formatted_paths = format_list_of_paths(paths);
+        net.add_node(non_connected_node(simple_point(C))).expect("should add C");
+        assert!(net.validate().is_ok(), "C should be found once added");
+    }
 
-        assert_eq!(formatted_paths, "A-B-C + A-B-D-C + A-D-B-C + A-D-C", "should find the four feasible paths");
+    fn total_weight(net: &Net<SimplePoint>) -> f64 {
+        net.edges()
+            .map(|(from, to)| net.find_node_or_throws(from).ok().and_then(|node| node.weight_to(to)).unwrap())
+            .sum()
     }
 
+    fn format_paths_with_cost(paths: &[Path<SimplePoint>]) -> Vec<String> {
+        paths.iter()
+            .map(|path| format!("{} ({})", path, path.cost()))
+            .collect()
+    }
 
     fn format_path_kebab(path: &Path<SimplePoint>) -> String {
-        return format!("{}", path);
+        format!("{}", path)
     }
 
     fn format_list_of_paths(paths: Vec<Path<SimplePoint>>) -> String {
         let mut formatted_and_ordered_paths: Vec<String> = paths.iter()
-            .map(|path| format_path_kebab(path))
+            .map(format_path_kebab)
             .collect();
 
         formatted_and_ordered_paths.sort();
@@ -305,8 +5588,76 @@ mod test {
         formatted_and_ordered_paths[..].join(" + ")
     }
 
+    fn a_b_c_net() -> Net<SimplePoint> {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+
+        Net::new(vec![
+            node(point_a, point_b),
+            node_connected_to(point_b, vec![point_a, point_c]),
+            node(point_c, point_b),
+        ])
+    }
+
+    fn triangle_net() -> Net<SimplePoint> {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        Net::new(vec![
+            node_connected_to(point_a, vec![point_b, point_d]),
+            node_connected_to(point_b, vec![point_a, point_c]),
+            node_connected_to(point_c, vec![point_b, point_d]),
+            node_connected_to(point_d, vec![point_a, point_c]),
+        ])
+    }
+
+    fn diamond_net() -> Net<SimplePoint> {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        Net::new(vec![
+            node_connected_to(point_a, vec![point_b, point_d]),
+            node_connected_to(point_b, vec![point_a, point_c, point_d]),
+            node_connected_to(point_c, vec![point_b, point_d]),
+            node_connected_to(point_d, vec![point_a, point_c, point_b]),
+        ])
+    }
+
+    fn complete_net() -> Net<SimplePoint> {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        Net::new(vec![
+            node_connected_to(point_a, vec![point_b, point_c, point_d]),
+            node_connected_to(point_b, vec![point_a, point_c, point_d]),
+            node_connected_to(point_c, vec![point_a, point_b, point_d]),
+            node_connected_to(point_d, vec![point_a, point_b, point_c]),
+        ])
+    }
+
+    fn weighted_triangle_net(b_c_weight: f64, a_d_weight: f64) -> Net<SimplePoint> {
+        let point_a = simple_point(A);
+        let point_b = simple_point(B);
+        let point_c = simple_point(C);
+        let point_d = simple_point(D);
+
+        Net::new(vec![
+            weighted_node(point_a, vec![(point_b, 1.0), (point_d, a_d_weight)]),
+            weighted_node(point_b, vec![(point_a, 1.0), (point_c, b_c_weight)]),
+            weighted_node(point_c, vec![(point_b, b_c_weight), (point_d, 1.0)]),
+            weighted_node(point_d, vec![(point_a, a_d_weight), (point_c, 1.0)]),
+        ])
+    }
+
     fn simple_point(name: char) -> SimplePoint {
-        SimplePoint { name: name.clone() }
+        SimplePoint { name }
     }
 
     fn node(from: SimplePoint, to: SimplePoint) -> Node<SimplePoint> {
@@ -325,6 +5676,36 @@ mod test {
             .unwrap()
     }
 
+    fn weighted_node(point: SimplePoint, weighted_connections: Vec<(SimplePoint, f64)>) -> Node<SimplePoint> {
+        let mut builder = NodeBuilder::new();
+        builder.point(&point);
+        for (connected_point, weight) in weighted_connections {
+            builder.connected_point_with_weight(&connected_point, weight);
+        }
+
+        builder.build().unwrap()
+    }
+
+    fn parallel_weighted_node(point: SimplePoint, weighted_connections: Vec<(SimplePoint, f64)>) -> Node<SimplePoint> {
+        let mut builder = NodeBuilder::new();
+        builder.point(&point);
+        for (connected_point, weight) in weighted_connections {
+            builder.parallel_connected_point_with_weight(&connected_point, weight);
+        }
+
+        builder.build().unwrap()
+    }
+
+    fn directed_node(point: SimplePoint, points_connected: Vec<SimplePoint>) -> Node<SimplePoint> {
+        let mut builder = NodeBuilder::new();
+        builder.point(&point);
+        for connected_point in points_connected {
+            builder.directed_connected_point(&connected_point);
+        }
+
+        builder.build().unwrap()
+    }
+
     fn non_connected_node(point: SimplePoint) -> Node<SimplePoint> {
         NodeBuilder::new()
             .point(&point)
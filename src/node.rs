@@ -1,18 +1,54 @@
 use path::Path;
+use std::hash::Hash;
+use std::mem;
 
 pub trait Point: Clone {
-    type Identifier: PartialEq + ToString;
+    type Identifier: Ord + Hash + Clone + ToString;
 
-    fn id(&self) -> Self::Identifier;
+    fn id(&self) -> &Self::Identifier;
 
     fn is(&self, other_point: &Self) -> bool {
-        &self.id() == &other_point.id()
+        self.id() == other_point.id()
     }
 }
 
-#[derive(Debug)]
+/// Point that is nothing but its id, to build nets of strings or numbers without declaring a
+/// type: `IdPoint::new("A")`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IdPoint<I> {
+    id: I,
+}
+
+impl<I> IdPoint<I> {
+    pub fn new(id: I) -> IdPoint<I> {
+        IdPoint { id }
+    }
+}
+
+impl<I: Clone + Ord + Hash + ToString> Point for IdPoint<I> {
+    type Identifier = I;
+
+    fn id(&self) -> &I {
+        &self.id
+    }
+}
+
+/// Characters are points identified by themselves, as in the nets read by `Net::from_dot`.
+impl Point for char {
+    type Identifier = char;
+
+    fn id(&self) -> &char {
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct Connection<T: Point> {
-    pub to: T
+    pub to: T,
+    pub weight: f64,
+    pub directed: bool,
 }
 
 impl<T: Point> Connection<T> {
@@ -24,13 +60,20 @@ impl<T: Point> Connection<T> {
 impl<T: Point> PartialEq for Connection<T> {
     fn eq(&self, other_connection: &Connection<T>) -> bool {
         self.to.is(&other_connection.to)
+            && self.weight == other_connection.weight
+            && self.directed == other_connection.directed
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Node<T: Point> {
     point: T,
     connections: Vec<Connection<T>>,
+    /// Ids of points connected through `NodeBuilder::connected_id` that the net has not found a
+    /// node for yet.
+    #[cfg_attr(feature = "serde", serde(skip, default = "Vec::new"))]
+    connected_ids: Vec<T::Identifier>,
 }
 
 impl<T: Point> Node<T> {
@@ -38,15 +81,129 @@ impl<T: Point> Node<T> {
         self.point.is(point)
     }
 
+    pub(crate) fn point(&self) -> &T {
+        &self.point
+    }
+
+    pub(crate) fn connected_points(&self) -> Vec<&T> {
+        self.connections.iter()
+            .map(|connection| &connection.to)
+            .collect()
+    }
+
+    /// Point reached by the `index`th connection, in declaration order.
+    pub(crate) fn connected_point_at(&self, index: usize) -> &T {
+        &self.connections[index].to
+    }
+
+    /// Number of connections declared on this node, which for one-way connections is its
+    /// out-degree.
+    pub fn degree(&self) -> usize {
+        self.connections.len()
+    }
+
     pub fn is_connected_to(&self, point: &T) -> bool {
         self.connections.iter()
             .any(|conn| conn.is_connected_to(point))
     }
 
+    pub(crate) fn weighted_connected_points(&self) -> Vec<(&T, f64)> {
+        self.connections.iter()
+            .map(|connection| (&connection.to, connection.weight))
+            .collect()
+    }
+
+    pub(crate) fn is_directed_to(&self, point: &T) -> bool {
+        self.connections.iter()
+            .any(|connection| connection.is_connected_to(point) && connection.directed)
+    }
+
+    pub(crate) fn has_directed_connections(&self) -> bool {
+        self.connections.iter().any(|connection| connection.directed)
+    }
+
+    /// Weight of the cheapest connection to `point`, since parallel connections may join the
+    /// same points with different weights.
+    pub(crate) fn weight_to(&self, point: &T) -> Option<f64> {
+        self.connections.iter()
+            .filter(|connection| connection.is_connected_to(point))
+            .map(|connection| connection.weight)
+            .reduce(f64::min)
+    }
+
+    pub(crate) fn connect_to(&mut self, point: &T) {
+        self.connect_to_with_weight(point, 1.0)
+    }
+
+    pub(crate) fn connect_to_with_weight(&mut self, point: &T, weight: f64) {
+        if !self.is_connected_to(point) {
+            self.connections.push(Connection { to: point.clone(), weight, directed: false });
+        }
+    }
+
+    /// Adds the connections of `other` to points this node is not connected to yet.
+    pub(crate) fn merge_connections(&mut self, other: Node<T>) {
+        for connection in other.connections {
+            if !self.is_connected_to(&connection.to) {
+                self.connections.push(connection);
+            }
+        }
+        for id in other.connected_ids {
+            if !self.connected_ids.contains(&id) {
+                self.connected_ids.push(id);
+            }
+        }
+    }
+
+    /// Replaces the connections to the same point by the first of them, weighing what folding
+    /// their weights with `combine` gives.
+    pub(crate) fn merge_parallel_connections<F: Fn(f64, f64) -> f64>(&mut self, combine: F) {
+        let mut merged_connections: Vec<Connection<T>> = Vec::new();
+        for connection in mem::take(&mut self.connections) {
+            match merged_connections.iter_mut().find(|merged| merged.is_connected_to(&connection.to)) {
+                Some(merged) => merged.weight = combine(merged.weight, connection.weight),
+                None => merged_connections.push(connection)
+            }
+        }
+        self.connections = merged_connections;
+    }
+
+    /// Copy of the node where every connection weighs what `new_weight` gives for its weight.
+    pub(crate) fn with_weights<F: Fn(f64) -> f64>(&self, new_weight: F) -> Node<T> {
+        let connections = self.connections.iter()
+            .map(|connection| Connection { weight: new_weight(connection.weight), ..connection.clone() })
+            .collect();
+
+        Node { point: self.point.clone(), connections, connected_ids: self.connected_ids.clone() }
+    }
+
+    pub(crate) fn connected_ids(&self) -> &[T::Identifier] {
+        &self.connected_ids
+    }
+
+    /// Turns the ids `find_point` knows the point of into connections, keeping the others.
+    pub(crate) fn resolve_connected_ids<F: Fn(&T::Identifier) -> Option<T>>(&mut self, find_point: F) {
+        for id in mem::take(&mut self.connected_ids) {
+            match find_point(&id) {
+                Some(point) => self.connect_to(&point),
+                None => self.connected_ids.push(id)
+            }
+        }
+    }
+
+    pub(crate) fn disconnect_from(&mut self, point: &T) {
+        self.connections.retain(|connection| !connection.is_connected_to(point));
+    }
+
     pub fn connected_points_not_in_path(&self, path: &Path<T>) -> Option<Vec<&T>> {
-        let points: Vec<&T> = self.connections.iter()
+        self.weighted_points_not_in_path(path)
+            .map(|points| points.into_iter().map(|(point, _)| point).collect())
+    }
+
+    pub(crate) fn weighted_points_not_in_path(&self, path: &Path<T>) -> Option<Vec<(&T, f64)>> {
+        let points: Vec<(&T, f64)> = self.connections.iter()
             .filter(|connection| path.do_not_contains(&connection.to))
-            .map(|c| &c.to)
+            .map(|c| (&c.to, c.weight))
             .collect();
 
         if points.is_empty() {
@@ -63,21 +220,28 @@ impl<T: Point> PartialEq for Node<T> {
             return false;
         }
 
-        self.connections == other_node.connections
+        self.connections == other_node.connections && self.connected_ids == other_node.connected_ids
     }
 }
 
 #[derive(Debug)]
 pub struct NodeBuilder<T: Point> {
     point: Option<T>,
-    connected_points: Option<Vec<T>>,
+    connected_points: Option<Vec<Connection<T>>>,
+    connected_ids: Vec<T::Identifier>,
+}
+
+impl<T: Point> Default for NodeBuilder<T> {
+    fn default() -> NodeBuilder<T> {
+        NodeBuilder::new()
+    }
 }
 
 impl<T: Point> NodeBuilder<T> {
     pub fn new() -> NodeBuilder<T> {
         let point = None;
         let connections = None;
-        NodeBuilder { point, connected_points: connections }
+        NodeBuilder { point, connected_points: connections, connected_ids: Vec::new() }
     }
 
     pub fn point(&mut self, point: &T) -> &mut Self {
@@ -88,11 +252,42 @@ impl<T: Point> NodeBuilder<T> {
     }
 
     pub fn connected_point(&mut self, point: &T) -> &mut Self {
+        self.connected_point_with_weight(point, 1.0)
+    }
+
+    pub fn connected_point_with_weight(&mut self, point: &T, weight: f64) -> &mut Self {
+        self.add_connection(point, weight, false)
+    }
+
+    /// Adds a one-way connection: paths can go from this node's point to `point` but, unless
+    /// the node of `point` declares its own connection back, not the other way around. A
+    /// bidirectional edge can be built from two directed connections, one on each node.
+    pub fn directed_connected_point(&mut self, point: &T) -> &mut Self {
+        self.directed_connected_point_with_weight(point, 1.0)
+    }
+
+    pub fn directed_connected_point_with_weight(&mut self, point: &T, weight: f64) -> &mut Self {
+        self.add_connection(point, weight, true)
+    }
+
+    /// Adds another undirected connection to `point` even if the node already has one, so that
+    /// the net becomes a multigraph where both connections can be followed. The other connection
+    /// methods keep a single connection per point, see `Net::merge_parallel_edges` to go back
+    /// to that.
+    pub fn parallel_connected_point_with_weight(&mut self, point: &T, weight: f64) -> &mut Self {
+        self.push_connection(point, weight, false)
+    }
+
+    fn add_connection(&mut self, point: &T, weight: f64, directed: bool) -> &mut Self {
         if self.node_is_connected_to(point) {
             return self;
         }
 
-        let point_connected = point.clone();
+        self.push_connection(point, weight, directed)
+    }
+
+    fn push_connection(&mut self, point: &T, weight: f64, directed: bool) -> &mut Self {
+        let point_connected = Connection { to: point.clone(), weight, directed };
         match self.connected_points {
             Some(ref mut c) => c.push(point_connected),
             None => self.connected_points = Some(vec![point_connected])
@@ -101,7 +296,20 @@ impl<T: Point> NodeBuilder<T> {
         self
     }
 
-    pub fn connected_points(&mut self, connected_points: &Vec<T>) -> &mut Self {
+    /// Connects the node to the point of id `id` when only the id is at hand. The connection is
+    /// made once the node is in a `Net` that has a node for that id; until then `Net::validate`
+    /// fails with `PointNotFound`.
+    pub fn connected_id(&mut self, id: T::Identifier) -> &mut Self {
+        let already_connected = self.connected_ids.contains(&id)
+            || self.connected_points.iter().flatten().any(|connection| *connection.to.id() == id);
+        if !already_connected {
+            self.connected_ids.push(id);
+        }
+
+        self
+    }
+
+    pub fn connected_points(&mut self, connected_points: &[T]) -> &mut Self {
         connected_points.iter()
             .for_each(|connected_to| {
                 self.connected_point(connected_to);
@@ -115,29 +323,29 @@ impl<T: Point> NodeBuilder<T> {
             return Err(String::from("Should specify a point"));
         }
 
-        if self.node_is_connected_to(self.point.as_ref().unwrap()) {
+        let point_id = self.point.as_ref().unwrap().id();
+        if self.node_is_connected_to(self.point.as_ref().unwrap()) || self.connected_ids.contains(point_id) {
             return Err(String::from("Point cannot be connected to itself"));
         }
 
+        if self.connected_points.iter().flatten().any(|connection| connection.weight.is_nan()) {
+            return Err(String::from("Connection weight should be a number"));
+        }
+
         let point = self.point
             .as_ref()
             .unwrap()
             .clone();
 
-        let to_connection = |connected_point: &T| Connection {
-            to: connected_point.clone()
-        };
-
         let connections = self.connected_points
             .as_ref()
             .unwrap_or(&Vec::new())
-            .iter()
-            .map(to_connection)
-            .collect();
+            .to_vec();
 
         Ok(Node {
             point,
             connections,
+            connected_ids: self.connected_ids.clone(),
         })
     }
 
@@ -145,7 +353,7 @@ impl<T: Point> NodeBuilder<T> {
         match self.connected_points {
             None => false,
             Some(ref connections) => connections.iter()
-                .any(|connected_point| connected_point.is(point))
+                .any(|connection| connection.is_connected_to(point))
         }
     }
 }
@@ -162,8 +370,8 @@ mod test {
     impl Point for Country {
         type Identifier = String;
 
-        fn id(&self) -> String {
-            self.name.to_string()
+        fn id(&self) -> &String {
+            &self.name
         }
     }
 
@@ -181,9 +389,10 @@ mod test {
         let iceland_node = Node {
             point: iceland,
             connections: Vec::new(),
+            connected_ids: Vec::new(),
         };
 
-        assert_eq!(iceland_node.is_connected_to(&austria), false);
+        assert!(!iceland_node.is_connected_to(&austria));
     }
 
     #[test]
@@ -194,11 +403,14 @@ mod test {
         let portugal_node = Node {
             point: portugal,
             connections: vec![Connection {
-                to: spain.clone()
+                to: spain.clone(),
+                weight: 1.0,
+                directed: false,
             }],
+            connected_ids: Vec::new(),
         };
 
-        assert_eq!(portugal_node.is_connected_to(&spain), true);
+        assert!(portugal_node.is_connected_to(&spain));
     }
 
     #[test]
@@ -209,15 +421,21 @@ mod test {
         let portugal_node = Node {
             point: portugal.clone(),
             connections: vec![Connection {
-                to: spain.clone()
+                to: spain.clone(),
+                weight: 1.0,
+                directed: false,
             }],
+            connected_ids: Vec::new(),
         };
 
         let other_portugal_node = Node {
             point: portugal.clone(),
             connections: vec![Connection {
-                to: spain.clone()
+                to: spain.clone(),
+                weight: 1.0,
+                directed: false,
             }],
+            connected_ids: Vec::new(),
         };
 
         assert_eq!(portugal_node, other_portugal_node);
@@ -237,8 +455,11 @@ mod test {
         let expected_portugal_node = Node {
             point: portugal,
             connections: vec![Connection {
-                to: spain.clone()
+                to: spain.clone(),
+                weight: 1.0,
+                directed: false,
             }],
+            connected_ids: Vec::new(),
         };
 
         assert_eq!(portugal_node, expected_portugal_node);
@@ -262,18 +483,93 @@ mod test {
             point: spain,
             connections: vec![
                 Connection {
-                    to: portugal.clone()
+                    to: portugal.clone(),
+                    weight: 1.0,
+                    directed: false,
                 },
                 Connection {
-                    to: france.clone()
+                    to: france.clone(),
+                    weight: 1.0,
+                    directed: false,
                 }
             ],
+            connected_ids: Vec::new(),
         };
 
         print!("pero qué me estás contando {:?}", spain_node);
         assert_eq!(spain_node, expected_spain_node, "Spain should be connected once to Portugal and France");
     }
 
+    #[test]
+    fn builder_should_build_a_node_with_weighted_connections() {
+        let portugal = get_country(PORTUGAL);
+        let spain = get_country(SPAIN);
+        let france = get_country(FRANCE);
+
+        let spain_node = NodeBuilder::new()
+            .point(&spain)
+            .connected_point_with_weight(&portugal, 620.0)
+            .connected_point(&france)
+            .build()
+            .expect("should build spain node");
+
+        let expected_spain_node = Node {
+            point: spain,
+            connections: vec![
+                Connection {
+                    to: portugal.clone(),
+                    weight: 620.0,
+                    directed: false,
+                },
+                Connection {
+                    to: france.clone(),
+                    weight: 1.0,
+                    directed: false,
+                }
+            ],
+            connected_ids: Vec::new(),
+        };
+
+        assert_eq!(spain_node, expected_spain_node, "Unweighted connections should default to a weight of 1");
+    }
+
+    #[test]
+    fn builder_should_build_a_node_with_a_directed_connection() {
+        let portugal = get_country(PORTUGAL);
+        let spain = get_country(SPAIN);
+
+        let portugal_node = NodeBuilder::new()
+            .point(&portugal)
+            .directed_connected_point(&spain)
+            .build()
+            .expect("should build portugal node");
+
+        let expected_portugal_node = Node {
+            point: portugal,
+            connections: vec![Connection {
+                to: spain.clone(),
+                weight: 1.0,
+                directed: true,
+            }],
+            connected_ids: Vec::new(),
+        };
+
+        assert_eq!(portugal_node, expected_portugal_node);
+        assert!(portugal_node.is_directed_to(&spain), "Portugal should only be connected one way to Spain");
+    }
+
+    #[test]
+    fn builder_should_fail_if_a_weight_is_not_a_number() {
+        let portugal = get_country(PORTUGAL);
+        let spain = get_country(SPAIN);
+
+        let mut builder = NodeBuilder::new();
+        let builder = builder.point(&spain);
+        let builder = builder.connected_point_with_weight(&portugal, f64::NAN);
+
+        assert_eq!(builder.build(), Err(String::from("Connection weight should be a number")));
+    }
+
     #[test]
     fn builder_should_fail_if_there_is_no_point() {
         let country_node_builder: NodeBuilder<Country> = NodeBuilder::new();
@@ -292,6 +588,45 @@ mod test {
         assert_eq!(builder.build(), Err(String::from("Point cannot be connected to itself")));
     }
 
+    #[test]
+    fn builder_should_fail_if_point_has_a_one_way_connection_to_itself() {
+        let iceland = get_country(ICELAND);
+        let spain = get_country(SPAIN);
+
+        let mut builder = NodeBuilder::new();
+        let builder = builder.point(&iceland);
+        let builder = builder.connected_points(&[spain]);
+        let builder = builder.directed_connected_point(&iceland);
+
+        assert_eq!(builder.build(), Err(String::from("Point cannot be connected to itself")));
+    }
+
+    #[test]
+    fn builder_should_fail_if_point_is_connected_to_its_own_id() {
+        let iceland = get_country(ICELAND);
+
+        let mut builder = NodeBuilder::new();
+        let builder = builder.point(&iceland);
+        let builder = builder.connected_id(String::from(ICELAND));
+
+        assert_eq!(builder.build(), Err(String::from("Point cannot be connected to itself")));
+    }
+
+    #[test]
+    fn id_points_should_be_connected_through_their_ids() {
+        let a = IdPoint::new("A");
+        let b = IdPoint::new("B");
+
+        let node = NodeBuilder::new()
+            .point(&a)
+            .connected_point(&b)
+            .build()
+            .expect("Should build a node of id points");
+
+        assert!(node.is_connected_to(&IdPoint::new("B")), "Should find B by its id");
+        assert_eq!(*node.point().id(), "A");
+    }
+
     fn get_country(name: &str) -> Country {
         Country {
             name: String::from(name)
@@ -1,18 +1,94 @@
+use net::Net;
+use net::NetErrors;
 use node::Point;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Path<T: Point> {
-    points: Vec<T>
+    points: Vec<T>,
+    weights: Vec<f64>,
 }
 
 impl<T: Point> Path<T> {
     pub fn push(&mut self, point: T) {
+        self.push_with_weight(point, 1.0);
+    }
+
+    pub fn push_with_weight(&mut self, point: T, weight: f64) {
+        if !self.points.is_empty() {
+            self.weights.push(weight);
+        }
         self.points.push(point);
     }
 
+    /// Sum of the weights of the connections followed by the path, zero for a single point.
+    pub fn cost(&self) -> f64 {
+        if self.weights.is_empty() {
+            0.0
+        } else {
+            self.weights.iter().sum()
+        }
+    }
+
+    pub(crate) fn hops(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Number of points in the path, including both ends.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    pub fn origin(&self) -> Option<&T> {
+        self.points.first()
+    }
+
+    pub fn destination(&self) -> Option<&T> {
+        self.points.last()
+    }
+
+    /// Points strictly between the origin and the destination.
+    pub fn intermediates(&self) -> &[T] {
+        if self.points.len() <= 2 {
+            &[]
+        } else {
+            &self.points[1..self.points.len() - 1]
+        }
+    }
+
+    /// Shorter paths first, then paths compared point by point through their ids.
+    pub(crate) fn cmp_by_hops_then_ids(&self, other: &Path<T>) -> Ordering {
+        self.hops().cmp(&other.hops())
+            .then_with(|| self.points.iter().map(Point::id).cmp(other.points.iter().map(Point::id)))
+    }
+
+    pub fn contains(&self, point_to_check: &T) -> bool {
+        self.position(point_to_check).is_some()
+    }
+
     pub fn do_not_contains(&self, point_to_check: &T) -> bool {
-        !self.points.iter().any(|point_in_path| point_in_path.is(point_to_check))
+        !self.contains(point_to_check)
+    }
+
+    /// Index of `point` in the path, the origin being at 0.
+    pub fn position(&self, point: &T) -> Option<usize> {
+        self.points.iter().position(|point_in_path| point_in_path.is(point))
+    }
+
+    pub(crate) fn point_before_last(&self) -> Option<&T> {
+        self.points.iter().rev().nth(1)
     }
 
     pub fn ends_with(&self, point: &T) -> bool {
@@ -22,46 +98,241 @@ impl<T: Point> Path<T> {
         }
     }
 
+    /// True when both paths go through the same points, in the same or in the reverse order.
+    pub fn same_route_as(&self, other: &Path<T>) -> bool {
+        if self.points.len() != other.points.len() {
+            return false;
+        }
+
+        let same_order = self.points.iter()
+            .zip(other.points.iter())
+            .all(|(point, other_point)| point.is(other_point));
+
+        let reverse_order = self.points.iter()
+            .zip(other.points.iter().rev())
+            .all(|(point, other_point)| point.is(other_point));
+
+        same_order || reverse_order
+    }
+
+    /// Points in `[start, end)` as a new path, or `None` if the range is empty or out of bounds.
+    pub fn slice(&self, start: usize, end: usize) -> Option<Path<T>> {
+        if start >= end || end > self.points.len() {
+            return None;
+        }
+
+        Some(Path {
+            points: self.points[start..end].to_vec(),
+            weights: self.weights[start..end - 1].to_vec(),
+        })
+    }
+
+    /// Builds a simple path through `points`, failing if any of them is repeated.
+    pub fn try_from_points(points: &[&T]) -> Result<Path<T>, String> {
+        let path = PathBuilder::new().points(points).build()?;
+
+        for (position, point) in points.iter().enumerate() {
+            if points[..position].iter().any(|previous_point| previous_point.is(point)) {
+                return Err(format!("Point {} is repeated in the path", point.id().to_string()));
+            }
+        }
+
+        Ok(path)
+    }
+
+    /// Same points in the opposite order, each connection keeping its weight.
+    pub fn reversed(&self) -> Path<T> {
+        Path {
+            points: self.points.iter().rev().cloned().collect(),
+            weights: self.weights.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// Path following this one and then `other`, which should start where this one ends.
+    /// Fails if the paths are not joined or if a point would be visited twice.
+    pub fn concat(&self, other: &Path<T>) -> Result<Path<T>, NetErrors> {
+        match (self.points.last(), other.points.first()) {
+            (Some(last_point), Some(first_point)) if last_point.is(first_point) => (),
+            _ => return Err(NetErrors::PathCannotBeBuilt(String::from("Paths should share their joining point")))
+        }
+
+        if let Some(repeated_point) = other.points[1..].iter().find(|point| !self.do_not_contains(point)) {
+            return Err(NetErrors::PathCannotBeBuilt(format!("Point {} would be visited twice", repeated_point.id().to_string())));
+        }
+
+        let mut builder = PathBuilder::new();
+        builder.point(&self.points[0]);
+        let following_points = self.points[1..].iter().zip(&self.weights)
+            .chain(other.points[1..].iter().zip(&other.weights));
+        for (point, &weight) in following_points {
+            builder.point_with_weight(point, weight);
+        }
+
+        builder.build().map_err(NetErrors::PathCannotBeBuilt)
+    }
+
+    /// Same path without the detours between points that `net` connects directly: from every
+    /// point it jumps to the farthest later point it is connected to. Origin and destination
+    /// are kept and shortcuts weigh as much as their connection, so the result has fewer points
+    /// but does not always cost less. Points without a node in the net are never left early.
+    pub fn simplify(&self, net: &Net<T>) -> Path<T> {
+        let mut simplified = match self.points.first() {
+            Some(origin) => Path { points: vec![origin.clone()], weights: Vec::new() },
+            None => return self.clone()
+        };
+
+        let mut position = 0;
+        while position + 1 < self.points.len() {
+            let shortcut = net.find_node_or_throws(&self.points[position]).ok().and_then(|node| {
+                (position + 2..self.points.len()).rev()
+                    .find_map(|later_position| node.weight_to(&self.points[later_position]).map(|weight| (later_position, weight)))
+            });
+            let (next_position, weight) = shortcut.unwrap_or((position + 1, self.weights[position]));
+
+            simplified.push_with_weight(self.points[next_position].clone(), weight);
+            position = next_position;
+        }
+
+        simplified
+    }
+
+    /// Ids of the points joined by `separator`; `Display` uses `-`.
+    pub fn format_with(&self, separator: &str) -> String {
+        let points: Vec<String> = self.points.iter()
+            .map(|point| point.id().to_string())
+            .collect();
+
+        points.join(separator)
+    }
+
+    /// Same as `Display`, followed by the cost of the path, e.g. `A-B-C (cost: 3.5)`.
+    pub fn format_with_cost(&self) -> String {
+        format!("{} (cost: {})", self, self.cost())
+    }
+
     pub fn with_point_at_the_end(&self, point_to_add: &T) -> Path<T>{
+        self.with_weighted_point_at_the_end(point_to_add, 1.0)
+    }
+
+    pub fn with_weighted_point_at_the_end(&self, point_to_add: &T, weight: f64) -> Path<T>{
         let mut new_path = self.clone();
-        new_path.push(point_to_add.clone());
+        new_path.push_with_weight(point_to_add.clone(), weight);
         new_path
     }
 }
 
+/// Paths are equal when they visit the same points in the same order, whatever their weights.
+impl<T: Point> PartialEq for Path<T> {
+    fn eq(&self, other_path: &Path<T>) -> bool {
+        self.points.len() == other_path.points.len()
+            && self.points.iter().zip(&other_path.points).all(|(point, other_point)| point.is(other_point))
+    }
+}
+
+impl<T: Point> Eq for Path<T> {}
+
+impl<T: Point> Hash for Path<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for point in &self.points {
+            point.id().hash(state);
+        }
+    }
+}
+
 impl<T: Point> fmt::Display for Path<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format_with("-"))
+    }
+}
+
+/// Sequence of connected points that, unlike a `Path`, may go through the same point several
+/// times, see `Net::find_walks`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Walk<T: Point> {
+    points: Vec<T>,
+    cost: f64,
+}
+
+impl<T: Point> Walk<T> {
+    pub(crate) fn new(points: Vec<T>, cost: f64) -> Walk<T> {
+        Walk { points, cost }
+    }
+
+    /// Sum of the weights of the connections followed by the walk, once per time each is followed.
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    /// Number of points in the walk, counting every visit.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Shorter walks first, then walks compared point by point through their ids.
+    pub(crate) fn cmp_by_length_then_ids(&self, other: &Walk<T>) -> Ordering {
+        self.len().cmp(&other.len())
+            .then_with(|| self.points.iter().map(Point::id).cmp(other.points.iter().map(Point::id)))
+    }
+}
+
+impl<T: Point> fmt::Display for Walk<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let points: Vec<String> = self.points.iter()
             .map(|point| point.id().to_string())
             .collect();
 
-        let points_in_kebab_case = points[..].join("-");
-        write!(f, "{}", points_in_kebab_case)
+        write!(f, "{}", points.join("-"))
     }
 }
 
 pub struct PathBuilder<T: Point> {
-    points: Option<Vec<T>>
+    points: Option<Vec<T>>,
+    weights: Vec<f64>,
+}
+
+impl<T: Point> Default for PathBuilder<T> {
+    fn default() -> PathBuilder<T> {
+        PathBuilder::new()
+    }
 }
 
 impl<T: Point> PathBuilder<T> {
     pub fn new() -> PathBuilder<T> {
         let points = None;
-        PathBuilder { points }
+        PathBuilder { points, weights: Vec::new() }
     }
 
-    pub fn points(&mut self, points: Vec<T>) -> &mut Self {
-        match self.points {
-            Some(_) => self.points = Some(points),
-            None => self.points = Some(points)
-        }
+    pub fn points(&mut self, points: &[&T]) -> &mut Self {
+        points.iter()
+            .for_each(|point| {
+                self.point(point);
+            });
+
         self
     }
 
     pub fn point(&mut self, point: &T) -> &mut Self {
+        self.point_with_weight(point, 1.0)
+    }
+
+    /// Adds a point reached through a connection of the given weight. The weight is ignored for
+    /// the first point of the path.
+    pub fn point_with_weight(&mut self, point: &T, weight: f64) -> &mut Self {
         let point_to_add = point.clone();
         match self.points {
-            Some(ref mut p) => p.push(point_to_add),
+            Some(ref mut p) => {
+                p.push(point_to_add);
+                self.weights.push(weight);
+            },
             None => self.points = Some(vec![point_to_add])
         }
         self
@@ -73,13 +344,17 @@ impl<T: Point> PathBuilder<T> {
                 .as_ref()
                 .ok_or("Should set at least one point for the path")?
         );
-        Ok(Path { points })
+        Ok(Path { points, weights: self.weights.clone() })
     }
 }
 
 #[cfg(test)]
 mod test {
+    use net::Net;
+    use net::NetErrors;
+    use node::NodeBuilder;
     use path::PathBuilder;
+    use std::collections::HashSet;
     use node::Point;
     use path::Path;
 
@@ -91,8 +366,8 @@ mod test {
     impl Point for SimplePoint {
         type Identifier = u8;
 
-        fn id(&self) -> u8 {
-            self.id
+        fn id(&self) -> &u8 {
+            &self.id
         }
     }
 
@@ -116,7 +391,7 @@ mod test {
     #[test]
     fn builder_should_be_able_to_build_a_path_setting_points_as_vec() {
         let path = PathBuilder::new()
-            .points(vec![SimplePoint::new(8), SimplePoint::new(5)])
+            .points(&[&SimplePoint::new(8), &SimplePoint::new(5)])
             .build()
             .expect("Builder should not throw if all attributes are provided");
 
@@ -129,6 +404,247 @@ mod test {
         assert!(builder.build().is_err(), "Should throw an error if no point is provided");
     }
 
+    #[test]
+    fn a_path_and_its_reverse_should_be_the_same_route() {
+        let path = path_with_ids(vec![1, 2, 3]);
+        let reversed_path = path_with_ids(vec![3, 2, 1]);
+
+        assert!(path.same_route_as(&reversed_path), "1-2-3 and 3-2-1 should be the same route");
+    }
+
+    #[test]
+    fn paths_through_different_points_should_not_be_the_same_route() {
+        let path = path_with_ids(vec![1, 2, 3]);
+        let other_path = path_with_ids(vec![1, 4, 3]);
+
+        assert!(!path.same_route_as(&other_path), "1-2-3 and 1-4-3 should not be the same route");
+    }
+
+    // Given this net of points:
+    // 1 - 2 - 3 - 4
+    //  \_____/
+    #[test]
+    fn simplify_should_shortcut_between_directly_connected_points() {
+        let points: Vec<SimplePoint> = (1..=4).map(SimplePoint::new).collect();
+        let net = Net::new(vec![
+            NodeBuilder::new().point(&points[0]).connected_points(&[points[1], points[2]]).build().unwrap(),
+            NodeBuilder::new().point(&points[1]).connected_points(&[points[0], points[2]]).build().unwrap(),
+            NodeBuilder::new().point(&points[2]).connected_points(&[points[0], points[1], points[3]]).build().unwrap(),
+            NodeBuilder::new().point(&points[3]).connected_point(&points[2]).build().unwrap(),
+        ]);
+        let path = path_with_ids(vec![1, 2, 3, 4]);
+
+        let simplified_path = path.simplify(&net);
+
+        assert_eq!(format_path_with_dashes_between_ids(simplified_path.clone()), "1-3-4", "should skip 2");
+        assert_eq!(simplified_path.cost(), 2.0, "should follow two connections");
+    }
+
+    #[test]
+    fn slice_should_return_the_points_in_the_range() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        let sliced_path = path.slice(1, 3).expect("1..3 is within the path");
+
+        assert_eq!(format_path_with_dashes_between_ids(sliced_path), "2-4", "Should keep the second and third points");
+    }
+
+    #[test]
+    fn path_should_contain_the_points_it_goes_through() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        assert!(path.contains(&SimplePoint::new(4)), "1-2-4-3 goes through 4");
+        assert!(!path.contains(&SimplePoint::new(5)), "1-2-4-3 does not go through 5");
+    }
+
+    #[test]
+    fn position_should_count_from_the_origin() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        assert_eq!(path.position(&SimplePoint::new(1)), Some(0), "1 is the origin");
+        assert_eq!(path.position(&SimplePoint::new(4)), Some(2), "4 is the third point");
+        assert_eq!(path.position(&SimplePoint::new(5)), None, "5 is not in the path");
+    }
+
+    #[test]
+    fn intermediates_should_leave_out_both_ends() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        let intermediate_ids: Vec<u8> = path.intermediates().iter().map(|point| *point.id()).collect();
+
+        assert_eq!(intermediate_ids, vec![2, 4], "should keep the points between 1 and 3");
+        assert_eq!(path.origin().map(|point| *point.id()), Some(1), "should start at 1");
+        assert_eq!(path.destination().map(|point| *point.id()), Some(3), "should end at 3");
+        assert!(path_with_ids(vec![1, 2]).intermediates().is_empty(), "1-2 has no point in between");
+    }
+
+    #[test]
+    fn format_with_cost_should_append_the_cost_to_the_path() {
+        let path = PathBuilder::new()
+            .point(&SimplePoint::new(1))
+            .point_with_weight(&SimplePoint::new(2), 1.5)
+            .point_with_weight(&SimplePoint::new(3), 2.0)
+            .build()
+            .expect("Builder should not throw if all attributes are provided");
+
+        assert_eq!(path.format_with_cost(), "1-2-3 (cost: 3.5)", "Should add up both weights");
+        assert_eq!(path.to_string(), "1-2-3", "Display should not show the cost");
+    }
+
+    #[test]
+    fn slice_out_of_range_should_return_none() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        assert!(path.slice(2, 5).is_none(), "Should not slice past the end of the path");
+        assert!(path.slice(2, 2).is_none(), "Should not return an empty path");
+    }
+
+    #[test]
+    fn cost_should_sum_the_weights_of_the_path() {
+        let path = PathBuilder::new()
+            .point(&SimplePoint::new(8))
+            .point_with_weight(&SimplePoint::new(5), 2.5)
+            .point_with_weight(&SimplePoint::new(3), 1.0)
+            .build()
+            .expect("Builder should not throw if all attributes are provided");
+
+        assert_eq!(path.cost(), 3.5, "Should add up 2.5 and 1");
+    }
+
+    #[test]
+    fn cost_of_an_unweighted_path_should_be_its_number_of_hops() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        assert_eq!(path.cost(), 3.0, "Each connection should weight 1 by default");
+    }
+
+    #[test]
+    fn len_should_count_every_point_of_the_path() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        assert_eq!(path.len(), 4, "Should count the four points");
+        assert!(!path.is_empty(), "A built path should not be empty");
+    }
+
+    #[test]
+    fn points_should_be_in_the_order_of_the_path() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        let ids: Vec<u8> = path.points().iter().map(|point| *point.id()).collect();
+        assert_eq!(ids, vec![1, 2, 4, 3], "Should keep the order of the points");
+    }
+
+    #[test]
+    fn format_with_should_join_the_ids_with_the_separator() {
+        let path = path_with_ids(vec![1, 2, 4, 3]);
+
+        assert_eq!(path.format_with(" → "), "1 → 2 → 4 → 3", "Should use the arrow between points");
+        assert_eq!(path.format_with(","), "1,2,4,3", "Should use the comma between points");
+        assert_eq!(format!("{}", path), "1-2-4-3", "Display should keep the dash");
+    }
+
+    #[test]
+    fn reversed_path_should_go_backwards_keeping_the_weights() {
+        let path = PathBuilder::new()
+            .point(&SimplePoint::new(1))
+            .point_with_weight(&SimplePoint::new(5), 2.5)
+            .point_with_weight(&SimplePoint::new(3), 1.0)
+            .build()
+            .expect("Builder should not throw if all attributes are provided");
+
+        let reversed_path = path.reversed();
+
+        assert_eq!(format_path_with_dashes_between_ids(reversed_path.clone()), "3-5-1", "Should start from the last point");
+        assert_eq!(reversed_path.slice(0, 2).map(|slice| slice.cost()), Some(1.0), "3-5 should weight 1");
+    }
+
+    #[test]
+    fn concat_should_join_paths_sharing_a_point() {
+        let path = path_with_ids(vec![1, 2, 4]);
+        let other_path = path_with_ids(vec![4, 3]);
+
+        let joined_path = path.concat(&other_path).expect("Should join 1-2-4 and 4-3");
+
+        assert_eq!(format_path_with_dashes_between_ids(joined_path.clone()), "1-2-4-3", "Should keep the joining point once");
+        assert_eq!(joined_path.cost(), 3.0, "Should keep every connection");
+    }
+
+    #[test]
+    fn concat_should_throw_if_paths_are_not_joined() {
+        let path = path_with_ids(vec![1, 2]);
+        let other_path = path_with_ids(vec![4, 3]);
+
+        match path.concat(&other_path) {
+            Err(NetErrors::PathCannotBeBuilt(_)) => (),
+            _ => panic!("PathCannotBeBuilt error expected")
+        }
+    }
+
+    #[test]
+    fn concat_should_throw_if_a_point_would_be_repeated() {
+        let path = path_with_ids(vec![1, 2, 4]);
+        let other_path = path_with_ids(vec![4, 2, 3]);
+
+        match path.concat(&other_path) {
+            Err(NetErrors::PathCannotBeBuilt(_)) => (),
+            _ => panic!("PathCannotBeBuilt error expected")
+        }
+    }
+
+    #[test]
+    fn path_from_points_should_keep_their_order() {
+        let points = [SimplePoint::new(8), SimplePoint::new(5), SimplePoint::new(3)];
+
+        let path = Path::try_from_points(&[&points[0], &points[1], &points[2]])
+            .expect("Should build a path through different points");
+
+        assert_eq!(format_path_with_dashes_between_ids(path), "8-5-3", "Should build a path with all points in order");
+    }
+
+    #[test]
+    fn path_from_points_should_throw_naming_the_repeated_point() {
+        let points = [SimplePoint::new(8), SimplePoint::new(5), SimplePoint::new(3)];
+
+        let path = Path::try_from_points(&[&points[0], &points[1], &points[2], &points[1]]);
+
+        assert_eq!(path.err(), Some(String::from("Point 5 is repeated in the path")), "Should name point 5");
+    }
+
+    #[test]
+    fn paths_through_the_same_points_should_be_equal_whatever_their_weights() {
+        let path = path_with_ids(vec![1, 2, 4]);
+        let weighted_path = PathBuilder::new()
+            .point(&SimplePoint::new(1))
+            .point_with_weight(&SimplePoint::new(2), 2.5)
+            .point_with_weight(&SimplePoint::new(4), 1.5)
+            .build()
+            .expect("Builder should not throw if all attributes are provided");
+
+        assert_eq!(path, weighted_path, "Should compare only the points");
+        assert_ne!(path, path.reversed(), "Should compare the order of the points");
+    }
+
+    #[test]
+    fn equal_paths_should_be_deduplicated_in_a_hash_set() {
+        let paths: HashSet<Path<SimplePoint>> = vec![
+            path_with_ids(vec![1, 2, 4]),
+            path_with_ids(vec![1, 3, 4]),
+            path_with_ids(vec![1, 2, 4]),
+        ].into_iter().collect();
+
+        assert_eq!(paths.len(), 2, "Should keep 1-2-4 once");
+    }
+
+    fn path_with_ids(ids: Vec<u8>) -> Path<SimplePoint> {
+        let points: Vec<SimplePoint> = ids.into_iter().map(SimplePoint::new).collect();
+        let point_references: Vec<&SimplePoint> = points.iter().collect();
+
+        PathBuilder::new()
+            .points(&point_references)
+            .build()
+            .expect("Builder should not throw if all attributes are provided")
+    }
+
     fn format_path_with_dashes_between_ids(path: Path<SimplePoint>) -> String {
         let ids_as_string: Vec<String> = path.points
             .iter()